@@ -0,0 +1,99 @@
+// Terminal animation of the simulation days
+//
+// When enabled from the command line, the simulations hand each step to `frame`,
+// which redraws it in place on stderr with ANSI escapes, so the answers on stdout
+// are unaffected. A frame is only rendered when it is drawn, so the simulations
+// run at full speed when animation is off.
+
+use std::io::{self, Write};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_FPS: f64 = 20.0;
+
+// Frames taller than this only show their top rows, to fit on the screen
+pub const MAX_ROWS: usize = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    pub fps: f64,
+    // Only every skip-th frame is drawn
+    pub skip: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings { fps: DEFAULT_FPS, skip: 1 }
+    }
+}
+
+struct Animator {
+    settings: Settings,
+    num_frames: usize,
+    last_drawn: Option<Instant>,
+}
+
+static ANIMATOR: Mutex<Option<Animator>> = Mutex::new(None);
+
+pub fn enable(settings: Settings) {
+    assert!(settings.fps > 0.0 && settings.skip > 0, "invalid animation settings {:?}", settings);
+    *ANIMATOR.lock().unwrap() = Some(Animator { settings, num_frames: 0, last_drawn: None });
+}
+
+pub fn disable() {
+    *ANIMATOR.lock().unwrap() = None;
+}
+
+pub fn is_enabled() -> bool {
+    ANIMATOR.lock().unwrap().is_some()
+}
+
+// Draws the next step of a simulation, unless it is skipped
+pub fn frame<F: FnOnce() -> String>(render: F) {
+    if let Some(ref mut animator) = *ANIMATOR.lock().unwrap() {
+        animator.num_frames += 1;
+        if (animator.num_frames - 1).is_multiple_of(animator.settings.skip) {
+            animator.draw(&render());
+        }
+    }
+}
+
+// Draws the final step of a simulation, even if it would be skipped, and
+// starts the next animation from the top of the screen
+pub fn last_frame<F: FnOnce() -> String>(render: F) {
+    if let Some(ref mut animator) = *ANIMATOR.lock().unwrap() {
+        animator.draw(&render());
+        animator.num_frames = 0;
+        eprintln!();
+    }
+}
+
+impl Animator {
+    fn draw(&mut self, frame: &str) {
+        // Keep to the frame rate
+        let interval = Duration::from_secs_f64(1.0 / self.settings.fps);
+        if let Some(last_drawn) = self.last_drawn {
+            if let Some(wait) = interval.checked_sub(last_drawn.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_drawn = Some(Instant::now());
+
+        // Move to the top left, overwrite each line, then clear anything left below.
+        // The screen is cleared before the first frame of each animation.
+        let stderr = io::stderr();
+        let mut out = stderr.lock();
+        let mut text = String::new();
+        if self.num_frames <= 1 {
+            text.push_str("\x1b[2J");
+        }
+        text.push_str("\x1b[H");
+        for line in frame.lines().take(MAX_ROWS) {
+            text.push_str(line);
+            text.push_str("\x1b[K\n");
+        }
+        text.push_str("\x1b[J");
+        let _ = out.write_all(text.as_bytes()).and_then(|_| out.flush());
+    }
+}
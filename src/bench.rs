@@ -0,0 +1,92 @@
+// Simple benchmarking of the solutions
+//
+// The input is parsed once, then each part is run a number of times on it
+// to get the min/median/max wall time, without needing a benchmarking crate.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use solution::{Answer, Solution};
+
+// Timing statistics over a number of runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // Panics if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute stats from");
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Stats { min: samples[0], median, max: samples[samples.len() - 1] }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "min {:>12.3?}  median {:>12.3?}  max {:>12.3?}", self.min, self.median, self.max)
+    }
+}
+
+// The benchmark results for one part
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartBench {
+    pub part: u32,
+    pub answer: Answer,
+    pub stats: Stats,
+}
+
+// The benchmark results for one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bench {
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Duration,
+    pub parts: Vec<PartBench>,
+}
+
+// Runs the requested part(s) of already parsed input the given number of times
+pub fn bench_parsed<S: Solution>(input: &S::Input, part: Option<u32>, runs: usize) -> Vec<PartBench> {
+    assert!(runs > 0, "need at least one run to benchmark");
+    let parts: Vec<u32> = [1, 2].iter().cloned().filter(|p| part.is_none() || part == Some(*p)).collect();
+    parts.into_iter()
+        .map(|part| {
+            let solve = if part == 1 { S::part1 } else { S::part2 };
+            let mut samples = Vec::with_capacity(runs);
+            let mut answer = Answer::None;
+            for _ in 0..runs {
+                let start = Instant::now();
+                answer = solve(input);
+                samples.push(start.elapsed());
+            }
+            PartBench { part, answer, stats: Stats::from_samples(&samples) }
+        })
+        .collect()
+}
+
+impl fmt::Display for Bench {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let runs = if self.runs == 1 { "run" } else { "runs" };
+        writeln!(f, "Day {} ({} {} per part)", self.day, self.runs, runs)?;
+        write!(f, "Parse:  {:>12.3?}", self.parse)?;
+        for part in &self.parts {
+            write!(f, "\nPart {}: {}", part.part, part.stats)?;
+            // Multi-line answers would break up the table
+            if let Answer::Art(_) = part.answer {
+                continue;
+            }
+            write!(f, "  (answer: {})", part.answer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+// Solution to Day 1 puzzle
+// https://adventofcode.com/2022/day/1
+//
+// Usage:
+//   cargo run --bin day01 <filename> [top_n]
+//
+// NOTE: Part 2 adds up the calories of the top 3 elves, or the top <top_n> if given.
+//       The inventory is read a line at a time, so it can be larger than memory.
+//
+// Example usage:
+//   cargo run --bin day01 data/day01/test_input.txt
+//   cargo run -r --bin day01 data/day01/puzzle_input.txt 10
+
+use std::env;
+use std::process;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::{day01, solution};
+
+fn main() {
+    // Get the filename from the command line ("-" for stdin), else fall back to default
+    let args: Vec<String> = env::args().collect();
+    let filename = if args.len() > 1 { &args[1] } else { solution::default_input("data/day01/test_input.txt") };
+    let top = match args.get(2).map(|arg| arg.parse::<usize>()) {
+        Some(Ok(top)) if top > 0 => Some(top),
+        Some(_) => {
+            eprintln!("The number of elves must be a positive integer");
+            process::exit(2);
+        },
+        None => None,
+    };
+    if let Err(e) = day01::run(filename, None, top) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -4,36 +4,18 @@
 // Example usage:
 //   cargo run --bin data/day06/test_input.txt
 
-use std::collections::HashSet;
 use std::env;
-use std::iter::FromIterator;
-use std::fs;
+use std::process;
 
-fn get_marker(chars : &Vec<char>, streak_length: usize) {
-    for i in streak_length..=chars.len() {
-        let set = HashSet::<&char>::from_iter(
-            &chars[i-streak_length..i]
-        );
-        if set.len() == streak_length {
-            println!("Found marker at index {}", i);
-            break;
-        }
-    }    
-}
+extern crate advent_of_code_2022;
+use advent_of_code_2022::{day06, solution};
 
 fn main() {
-    // Get the filename from the command line, else fall back to default
+    // Get the filename from the command line ("-" for stdin), else fall back to default
     let args: Vec<String> = env::args().collect();
-    let filename = if args.len() > 1 { &args[1] } else { "data/day06/test_input.txt" };
-
-    // Read the file
-    let data = fs::read_to_string(filename).unwrap();
-    let chars = data.chars().collect::<Vec<char>>();
-
-    //  Look for markers
-    println!("PART 1:");
-    get_marker(&chars, 4);
-    println!("PART 2:");
-    get_marker(&chars, 14);
-
+    let filename = if args.len() > 1 { &args[1] } else { solution::default_input("data/day06/test_input.txt") };
+    if let Err(e) = day06::run(filename, None) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
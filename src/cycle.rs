@@ -0,0 +1,77 @@
+// Cycle detection, for simulations run for far more steps than is feasible
+//
+// Each step of a simulation is recorded as a state, which must capture everything
+// that decides how the simulation goes on, and a value such as a height or score.
+// Once a state comes round again the steps repeat, so the value at any later step
+// can be worked out from one pass through the cycle.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The steps from start up to start + length repeat forever
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct CycleDetector<S> {
+    first_seen: HashMap<S, usize>,
+    values: Vec<i64>,
+    cycle: Option<Cycle>,
+}
+
+impl<S: Eq + Hash> CycleDetector<S> {
+    pub fn new() -> Self {
+        CycleDetector { first_seen: HashMap::new(), values: Vec::new(), cycle: None }
+    }
+
+    // Records the state and value after the next step, starting from step 0.
+    // Gives the cycle once a state repeats; steps pushed after that are ignored.
+    pub fn push(&mut self, state: S, value: i64) -> Option<Cycle> {
+        if self.cycle.is_none() {
+            let step = self.values.len();
+            match self.first_seen.get(&state) {
+                Some(&start) => self.cycle = Some(Cycle { start, length: step - start }),
+                None => {
+                    self.first_seen.insert(state, step);
+                },
+            }
+            self.values.push(value);
+        }
+        self.cycle
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    // The number of steps recorded, up to and including the one that closed the cycle
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // The value at a step, which needs a cycle to have been found if the step was not recorded.
+    // Each pass through the cycle adds the same amount to the value.
+    pub fn extrapolate(&self, target_step: usize) -> Option<i64> {
+        if target_step < self.values.len() {
+            return Some(self.values[target_step]);
+        }
+        let cycle = self.cycle?;
+        let gain_per_cycle = self.values[cycle.start + cycle.length] - self.values[cycle.start];
+        let num_cycles = ((target_step - cycle.start) / cycle.length) as i64;
+        let offset = (target_step - cycle.start) % cycle.length;
+        Some(self.values[cycle.start + offset] + num_cycles * gain_per_cycle)
+    }
+}
+
+impl<S: Eq + Hash> Default for CycleDetector<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,128 @@
+// Solution to Day 1 puzzle
+// https://adventofcode.com/2022/day/1
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day01;
+
+// How many of the elves carrying the most calories are added up in Part 2, by default
+pub const DEFAULT_TOP: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elf {
+    // 1-indexed position in the inventory
+    pub index: usize,
+    pub calories: i64,
+}
+
+// Keeps the n elves carrying the most calories while the inventory is read a line at a time,
+// so only n elves are held in memory however long the inventory is.
+// Ties go to the elf that came first.
+pub struct Ranker {
+    n: usize,
+    // Min-heap of the top elves so far, where the last of the tied elves is the smallest
+    top: BinaryHeap<Reverse<(i64, Reverse<usize>)>>,
+    num_elves: usize,
+    // The running total of the elf whose items are being read, if any
+    current: Option<i64>,
+}
+
+impl Ranker {
+    pub fn new(n: usize) -> Self {
+        Ranker { n, top: BinaryHeap::with_capacity(n + 1), num_elves: 0, current: None }
+    }
+
+    // A blank line ends an elf's items, and any other line is one of them
+    pub fn push_line(&mut self, line_num: usize, line: &str) -> Result<(), ParseError> {
+        let line = line.trim_end();
+        if line.is_empty() {
+            self.end_elf();
+        } else {
+            let calories: i64 = error::parse_token(line_num, line, line, "a calorie count")?;
+            *self.current.get_or_insert(0) += calories;
+        }
+        Ok(())
+    }
+
+    fn end_elf(&mut self) {
+        if let Some(calories) = self.current.take() {
+            self.num_elves += 1;
+            self.top.push(Reverse((calories, Reverse(self.num_elves))));
+            if self.top.len() > self.n {
+                self.top.pop();
+            }
+        }
+    }
+
+    // The top elves, from the most calories to the least.
+    // The last elf may not be followed by a blank line.
+    pub fn finish(mut self) -> Vec<Elf> {
+        self.end_elf();
+        self.top.into_sorted_vec().into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect()
+    }
+}
+
+// Ranks the n elves carrying the most calories
+pub fn top_elves(data: &str, n: usize) -> Result<Vec<Elf>, ParseError> {
+    let mut ranker = Ranker::new(n);
+    for (i, line) in data.lines().enumerate() {
+        ranker.push_line(i + 1, line)?;
+    }
+    Ok(ranker.finish())
+}
+
+// Ranks the n elves carrying the most calories, streaming the inventory from a reader
+pub fn read_top_elves<R: BufRead>(reader: R, n: usize) -> error::Result<Vec<Elf>> {
+    let mut ranker = Ranker::new(n);
+    for (i, line) in reader.lines().enumerate() {
+        ranker.push_line(i + 1, &line?)?;
+    }
+    Ok(ranker.finish())
+}
+
+impl Solution for Day01 {
+    // The top elves, from the most calories to the least
+    type Input = Vec<Elf>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        top_elves(data, DEFAULT_TOP)
+    }
+
+    // Part 1: Find the elf carrying the most calories
+    fn part1(top: &Self::Input) -> Answer {
+        match top.first() {
+            Some(elf) => {
+                info!("Elf {} has the most calories ({})", elf.index, elf.calories);
+                Answer::from(elf.calories)
+            },
+            None => {
+                warn!("There are no elves in the inventory");
+                Answer::None
+            },
+        }
+    }
+
+    // Part 2: Find the total calories carried by the top elves
+    fn part2(top: &Self::Input) -> Answer {
+        for (n, elf) in top.iter().enumerate() {
+            info!("Top {} elf is elf {} with {} calories", n + 1, elf.index, elf.calories);
+        }
+        Answer::from(top.iter().map(|elf| elf.calories).sum::<i64>())
+    }
+}
+
+// If a number of elves is given, Part 2 adds up that many instead of the top 3
+pub fn run(filename: &str, part: Option<u32>, top: Option<usize>) -> error::Result<()> {
+    let reader = solution::open_input(filename)?;
+    let input = read_top_elves(reader, top.unwrap_or(DEFAULT_TOP))
+        .map_err(|e| e.in_file(solution::input_name(filename)))?;
+    solution::print_answers(&solution::solve_parsed::<Day01>(&input, part));
+    Ok(())
+}
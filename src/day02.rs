@@ -0,0 +1,447 @@
+// Solution to Day 2 puzzle
+// https://adventofcode.com/2022/day/2
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs;
+use std::io;
+
+use itertools::Itertools;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day02;
+
+// How many interpretations the search shows, by default
+pub const DEFAULT_TOP: usize = 10;
+
+// The most weapons the search tries every interpretation for, since there are N! of them
+pub const MAX_SEARCH_WEAPONS: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Weapon {
+    pub name: String,
+    // The letters for this weapon in the first and second columns of the guide
+    pub opponent_code: char,
+    pub my_code: char,
+    // Score for choosing this weapon
+    pub score: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+// What the second column of the strategy guide means
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Interpretation {
+    // The weapon to play, where the code of weapon i in the rules stands for weapon mapping[i].
+    // In Part 1 each code stands for its own weapon.
+    Moves(Vec<usize>),
+    // The outcome needed (Part 2)
+    Outcomes,
+}
+
+// A round of the guide, as played under an interpretation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Play {
+    pub theirs: usize,
+    pub mine: usize,
+    pub outcome: Outcome,
+    pub score: i32,
+}
+
+// The rules of a hand game, where any two different weapons have a winner
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    pub weapons: Vec<Weapon>,
+    // beats[i][j] is whether weapon i beats weapon j
+    beats: Vec<Vec<bool>>,
+    // The code and score of each outcome, in the order lose, draw, win
+    pub outcomes: [(char, i32); 3],
+}
+
+impl Rules {
+    // A game where each weapon beats the (N - 1) / 2 weapons before it, wrapping around,
+    // so every weapon wins against as many weapons as it loses to.
+    // This only works for an odd number of weapons.
+    pub fn cyclic(weapons: Vec<Weapon>, outcomes: [(char, i32); 3]) -> Result<Self, ParseError> {
+        let n = weapons.len();
+        if n.is_multiple_of(2) {
+            return Err(ParseError::new(1, 1, "an odd number of weapons for a cyclic game"));
+        }
+        let beats = (0..n)
+            .map(|i| (0..n).map(|j| j != i && (i + n - j) % n <= n / 2).collect())
+            .collect();
+        Ok(Rules { weapons, beats, outcomes })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        let weapon = |name: &str, opponent_code, my_code, score| {
+            Weapon { name: name.to_string(), opponent_code, my_code, score }
+        };
+        Rules::cyclic(
+            vec![weapon("rock", 'A', 'X', 1), weapon("paper", 'B', 'Y', 2), weapon("scissors", 'C', 'Z', 3)],
+            [('X', 0), ('Y', 3), ('Z', 6)])
+            .expect("rock paper scissors has 3 weapons")
+    }
+
+    // Reads a rules table, with one rule per line:
+    //   weapon <name> <opponent code> <my code> <score>
+    //   beats <name> <name>...
+    //   cyclic
+    //   outcome lose|draw|win <code> <score>
+    // Blank lines and lines starting with # are skipped. "cyclic" makes each weapon
+    // beat the ones before it in the order they are listed, as in Rules::cyclic.
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let mut weapons: Vec<Weapon> = Vec::new();
+        let mut beats_lines = Vec::new();
+        let mut is_cyclic = false;
+        let mut outcomes: [Option<(char, i32)>; 3] = [None; 3];
+
+        for (i, line) in data.lines().enumerate() {
+            let line_num = i + 1;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let code = |token: &str, what: &str| {
+                let mut chars = token.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if c.is_ascii_uppercase() => Ok(c),
+                    _ => Err(ParseError::new(line_num, error::column_of(line, token), what)),
+                }
+            };
+            match tokens.first() {
+                None => continue,
+                Some(token) if token.starts_with('#') => continue,
+                Some(&"weapon") if tokens.len() == 5 => {
+                    let weapon = Weapon {
+                        name: tokens[1].to_string(),
+                        opponent_code: code(tokens[2], "an opponent code from A to Z")?,
+                        my_code: code(tokens[3], "a code from A to Z")?,
+                        score: error::parse_token(line_num, line, tokens[4], "a score")?,
+                    };
+                    let clash = weapons.iter().position(|other| other.name == weapon.name ||
+                        other.opponent_code == weapon.opponent_code || other.my_code == weapon.my_code);
+                    if clash.is_some() {
+                        return Err(ParseError::new(line_num, error::column_of(line, tokens[1]),
+                            "a weapon with a new name and codes"));
+                    }
+                    weapons.push(weapon);
+                },
+                Some(&"weapon") => {
+                    return Err(ParseError::new(line_num, 1, "a rule like \"weapon rock A X 1\""));
+                },
+                Some(&"beats") if tokens.len() >= 3 => beats_lines.push((line_num, line)),
+                Some(&"cyclic") if tokens.len() == 1 => is_cyclic = true,
+                Some(&"outcome") if tokens.len() == 4 => {
+                    let idx = match tokens[1] {
+                        "lose" => 0,
+                        "draw" => 1,
+                        "win" => 2,
+                        _ => return Err(ParseError::new(line_num, error::column_of(line, tokens[1]),
+                            "lose, draw or win")),
+                    };
+                    let code = code(tokens[2], "an outcome code from A to Z")?;
+                    let score = error::parse_token(line_num, line, tokens[3], "a score")?;
+                    outcomes[idx] = Some((code, score));
+                },
+                Some(_) => {
+                    return Err(ParseError::new(line_num, 1,
+                        "a rule like \"weapon rock A X 1\", \"beats rock scissors\", \"cyclic\" or \"outcome win Z 6\""));
+                },
+            }
+        }
+
+        // The rules are checked as a whole at the end of the table
+        let end = data.lines().count() + 1;
+        let outcomes = match outcomes {
+            [Some(lose), Some(draw), Some(win)] if lose.0 != draw.0 && draw.0 != win.0 && lose.0 != win.0 =>
+                [lose, draw, win],
+            _ => return Err(ParseError::new(end, 1, "an outcome rule with a different code for each of lose, draw and win")),
+        };
+        if weapons.len() < 2 {
+            return Err(ParseError::new(end, 1, "at least 2 weapons"));
+        }
+        let n = weapons.len();
+        let mut rules = if is_cyclic {
+            Rules::cyclic(weapons, outcomes).map_err(|e| ParseError { line: end, ..e })?
+        } else {
+            Rules { weapons, beats: vec![vec![false; n]; n], outcomes }
+        };
+
+        for (line_num, line) in beats_lines {
+            let mut tokens = line.split_whitespace().skip(1);
+            let find = |rules: &Rules, token: &str| {
+                rules.weapons.iter().position(|weapon| weapon.name == token)
+                    .ok_or_else(|| ParseError::new(line_num, error::column_of(line, token), "the name of a weapon"))
+            };
+            let winner = find(&rules, tokens.next().unwrap())?;
+            for token in tokens {
+                let loser = find(&rules, token)?;
+                if loser == winner || rules.beats[loser][winner] {
+                    return Err(ParseError::new(line_num, error::column_of(line, token),
+                        "another weapon that does not already beat it"));
+                }
+                rules.beats[winner][loser] = true;
+            }
+        }
+
+        // Every game between two weapons needs a winner, and every weapon needs to be able to win and lose
+        for i in 0..n {
+            for j in 0..i {
+                if !rules.beats[i][j] && !rules.beats[j][i] {
+                    return Err(ParseError::new(end, 1, &format!("a rule for whether {} beats {}",
+                        rules.weapons[i].name, rules.weapons[j].name)));
+                }
+            }
+            if !rules.beats[i].iter().any(|&b| b) || !(0..n).any(|j| rules.beats[j][i]) {
+                return Err(ParseError::new(end, 1, &format!("rules where {} can both win and lose",
+                    rules.weapons[i].name)));
+            }
+        }
+        Ok(rules)
+    }
+
+    pub fn outcome(&self, mine: usize, theirs: usize) -> Outcome {
+        if self.beats[mine][theirs] {
+            Outcome::Win
+        } else if self.beats[theirs][mine] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn opponent_weapon(&self, code: char) -> Option<usize> {
+        self.weapons.iter().position(|weapon| weapon.opponent_code == code)
+    }
+
+    pub fn my_weapon(&self, code: char) -> Option<usize> {
+        self.weapons.iter().position(|weapon| weapon.my_code == code)
+    }
+
+    pub fn outcome_of_code(&self, code: char) -> Option<Outcome> {
+        self.outcomes.iter().position(|&(c, _)| c == code).map(|idx| OUTCOMES[idx])
+    }
+
+    // The weapon to play against the opponent's to get the outcome.
+    // If there is more than one, the one with the highest score is picked,
+    // and of those with the same score, the one listed first.
+    pub fn choose(&self, theirs: usize, outcome: Outcome) -> usize {
+        (0..self.weapons.len())
+            .filter(|&mine| self.outcome(mine, theirs) == outcome)
+            .max_by_key(|&mine| (self.weapons[mine].score, Reverse(mine)))
+            .unwrap()
+    }
+
+    // The score for playing one weapon against another
+    pub fn score(&self, mine: usize, theirs: usize) -> i32 {
+        self.weapons[mine].score + self.outcomes[self.outcome(mine, theirs) as usize].1
+    }
+
+    // Plays a round of the guide, if the second column means something under this interpretation
+    pub fn play_round(&self, round: (char, char), interpretation: &Interpretation) -> Option<Play> {
+        let theirs = self.opponent_weapon(round.0)?;
+        let mine = match interpretation {
+            Interpretation::Moves(mapping) => mapping[self.my_weapon(round.1)?],
+            Interpretation::Outcomes => self.choose(theirs, self.outcome_of_code(round.1)?),
+        };
+        Some(Play { theirs, mine, outcome: self.outcome(mine, theirs), score: self.score(mine, theirs) })
+    }
+
+    pub fn score_round(&self, round: (char, char), interpretation: &Interpretation) -> Option<i32> {
+        let play = self.play_round(round, interpretation)?;
+        trace!("{} vs. {}: {:?}, selection score: {}, score: {}", self.weapons[play.theirs].name,
+            self.weapons[play.mine].name, play.outcome, self.weapons[play.mine].score, play.score);
+        Some(play.score)
+    }
+
+    // Each code in the second column stands for its own weapon
+    pub fn moves_as_listed(&self) -> Interpretation {
+        Interpretation::Moves((0..self.weapons.len()).collect())
+    }
+
+    // Every way the codes in the second column could stand for the weapons, then the outcomes.
+    // They are made one at a time, as there are N! of them.
+    pub fn interpretations(&self) -> impl Iterator<Item = Interpretation> {
+        let n = self.weapons.len();
+        (0..n).permutations(n)
+            .map(Interpretation::Moves)
+            .chain(Some(Interpretation::Outcomes))
+    }
+
+    // Describes what each code stands for, like "X=rock Y=paper Z=scissors"
+    pub fn describe(&self, interpretation: &Interpretation) -> String {
+        match interpretation {
+            Interpretation::Moves(mapping) => self.weapons.iter().zip(mapping)
+                .map(|(weapon, &mine)| format!("{}={}", weapon.my_code, self.weapons[mine].name))
+                .join(" "),
+            Interpretation::Outcomes => self.outcomes.iter().zip(["lose", "draw", "win"].iter())
+                .map(|(&(code, _), outcome)| format!("{}={}", code, outcome))
+                .join(" "),
+        }
+    }
+}
+
+// Lists the codes as "A, B, or C"
+fn list_codes<I: Iterator<Item = char>>(codes: I) -> String {
+    let codes: Vec<String> = codes.map(|c| c.to_string()).collect();
+    match codes.split_last() {
+        Some((last, rest)) if rest.len() > 1 => format!("{}, or {}", rest.join(", "), last),
+        Some((last, rest)) if rest.len() == 1 => format!("{} or {}", rest[0], last),
+        _ => codes.join(""),
+    }
+}
+
+pub struct Input {
+    pub rules: Rules,
+    // The opponent's play and the second column for each round
+    pub rounds: Vec<(char, char)>,
+}
+
+// Reads a strategy guide for a game with the given rules
+pub fn parse_with_rules(data: &str, rules: Rules) -> Result<Input, ParseError> {
+    let mut second_codes: Vec<char> = rules.weapons.iter().map(|weapon| weapon.my_code)
+        .chain(rules.outcomes.iter().map(|&(code, _)| code))
+        .collect();
+    second_codes.sort();
+    second_codes.dedup();
+
+    let mut rounds = Vec::new();
+    for (i, line) in data.lines().enumerate() {
+        let v: Vec<char> = line.chars().collect();
+        // Assumes single space
+        if v.len() != 3 || rules.opponent_weapon(v[0]).is_none() {
+            return Err(ParseError::new(i + 1, 1, &format!("a play of {}",
+                list_codes(rules.weapons.iter().map(|weapon| weapon.opponent_code)))));
+        }
+        if v[1] != ' ' {
+            return Err(ParseError::new(i + 1, 2, "a single space"));
+        }
+        if !second_codes.contains(&v[2]) {
+            return Err(ParseError::new(i + 1, 3, &list_codes(second_codes.iter().cloned())));
+        }
+        rounds.push((v[0], v[2]));
+    }
+    Ok(Input { rules, rounds })
+}
+
+// Reads a rules table from a file
+pub fn load_rules(filename: &str) -> error::Result<Rules> {
+    let data = fs::read_to_string(filename)?;
+    Ok(Rules::parse(&data).map_err(|e| e.in_file(filename))?)
+}
+
+// Reads a strategy guide, for rock paper scissors unless a rules file is given
+pub fn load_input(filename: &str, rules_file: Option<&str>) -> error::Result<Input> {
+    let rules = match rules_file {
+        Some(rules_file) => load_rules(rules_file)?,
+        None => Rules::rock_paper_scissors(),
+    };
+    let data = solution::read_input(filename)?;
+    Ok(parse_with_rules(&data, rules).map_err(|e| e.in_file(solution::input_name(filename)))?)
+}
+
+// Tallies up the score of the guide, unless a round has no meaning under the interpretation
+pub fn total_score(input: &Input, interpretation: &Interpretation) -> Option<i32> {
+    let mut total = 0;
+    for (i, &round) in input.rounds.iter().enumerate() {
+        match input.rules.score_round(round, interpretation) {
+            Some(score) => total += score,
+            None => {
+                warn!("Round {} ({} {}) has no meaning when the second column is {}",
+                    i + 1, round.0, round.1, input.rules.describe(interpretation));
+                return None;
+            },
+        }
+    }
+    Some(total)
+}
+
+impl Solution for Day02 {
+    type Input = Input;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_with_rules(data, Rules::rock_paper_scissors())
+    }
+
+    // Part 1: The second column is the move to play
+    fn part1(input: &Self::Input) -> Answer {
+        total_score(input, &input.rules.moves_as_listed()).map_or(Answer::None, Answer::from)
+    }
+
+    // Part 2: The second column is the outcome needed
+    fn part2(input: &Self::Input) -> Answer {
+        total_score(input, &Interpretation::Outcomes).map_or(Answer::None, Answer::from)
+    }
+}
+
+// The total score of the guide under the top interpretations, from the highest to the lowest.
+// Interpretations where a round has no meaning come last, and ties go to the one tried first.
+// Only the top ones are kept while the rest are scored, and each different round is only
+// played once per interpretation, however many times it is in the guide.
+pub fn rank_interpretations(input: &Input, top: usize) -> Vec<(Interpretation, Option<i32>)> {
+    let tally = input.rounds.iter().cloned().counts();
+    // Min-heap of the top interpretations so far, where the last of the tied ones is the smallest
+    let mut ranking = BinaryHeap::with_capacity(top + 1);
+    for (idx, interpretation) in input.rules.interpretations().enumerate() {
+        let total: Option<i32> = tally.iter()
+            .map(|(&round, &count)| input.rules.play_round(round, &interpretation)
+                .map(|play| play.score * count as i32))
+            .sum();
+        ranking.push(Reverse((total, Reverse(idx), interpretation)));
+        if ranking.len() > top {
+            ranking.pop();
+        }
+    }
+    ranking.into_sorted_vec().into_iter()
+        .map(|Reverse((total, _, interpretation))| (interpretation, total))
+        .collect()
+}
+
+// If a rules file is given, the guide is for that game instead of rock paper scissors
+pub fn run(filename: &str, part: Option<u32>, rules_file: Option<&str>) -> error::Result<()> {
+    let input = load_input(filename, rules_file)?;
+    solution::print_answers(&solution::solve_parsed::<Day02>(&input, part));
+    Ok(())
+}
+
+// Prints the score of the guide under the top interpretations, ranked,
+// and how the best one scores each round
+pub fn search(filename: &str, rules_file: Option<&str>, top: usize) -> error::Result<()> {
+    let input = load_input(filename, rules_file)?;
+    let rules = &input.rules;
+    if rules.weapons.len() > MAX_SEARCH_WEAPONS {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+            format!("Too many weapons ({}) to try every interpretation, the most is {}",
+                rules.weapons.len(), MAX_SEARCH_WEAPONS)).into());
+    }
+    let ranking = rank_interpretations(&input, top);
+    println!("Rank  Score  Second column");
+    for (rank, (interpretation, total)) in ranking.iter().enumerate() {
+        let total = total.map_or("-".to_string(), |total| total.to_string());
+        println!("{:>4}  {:>5}  {}", rank + 1, total, rules.describe(interpretation));
+    }
+
+    let best = match ranking.first() {
+        Some((best, Some(_))) => best,
+        _ => return Ok(()),
+    };
+    println!("\nRounds when the second column is {}:", rules.describe(best));
+    let width = rules.weapons.iter().map(|weapon| weapon.name.len()).max().unwrap();
+    println!("Round  Guide  {:<w$}  {:<w$}  Outcome  Score  Total", "Opponent", "Me", w = width.max(8));
+    let mut total = 0;
+    for (i, &round) in input.rounds.iter().enumerate() {
+        let play = rules.play_round(round, best).unwrap();
+        total += play.score;
+        println!("{:>5}  {} {}    {:<w$}  {:<w$}  {:<7}  {:>5}  {:>5}", i + 1, round.0, round.1,
+            rules.weapons[play.theirs].name, rules.weapons[play.mine].name, format!("{:?}", play.outcome),
+            play.score, total, w = width.max(8));
+    }
+    Ok(())
+}
@@ -0,0 +1,204 @@
+// Solution to Day 3 puzzle
+// https://adventofcode.com/2022/day/3
+
+use std::fmt;
+
+use rayon::prelude::*;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day03;
+
+// How many elves are in each group, by default
+pub const GROUP_SIZE: usize = 3;
+
+// If the item is lower case, priority is 1..26
+// If the item is upper case, priority is 27..52
+pub fn get_item_priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+// A set of items, where the item with priority p is bit p - 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(pub u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    // The set of items in a line, skipping anything that is not an item
+    pub fn from_items(items: &str) -> Self {
+        ItemSet(items.chars()
+            .filter_map(get_item_priority)
+            .fold(0, |bits, priority| bits | 1 << (priority - 1)))
+    }
+
+    pub fn intersection(self, other: ItemSet) -> Self {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> Self {
+        ItemSet(self.0 | other.0)
+    }
+
+    // The items in all of the sets, or none if there are no sets
+    pub fn common<I: IntoIterator<Item = ItemSet>>(sets: I) -> Self {
+        let mut sets = sets.into_iter();
+        match sets.next() {
+            Some(first) => sets.fold(first, ItemSet::intersection),
+            None => ItemSet::EMPTY,
+        }
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        get_item_priority(item).is_some_and(|priority| self.0 & 1 << (priority - 1) != 0)
+    }
+
+    // The priorities of the items, from lowest to highest
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |priority| self.0 & 1 << (priority - 1) != 0)
+    }
+
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().map(|priority| match priority {
+            1..=26 => (b'a' + priority as u8 - 1) as char,
+            _ => (b'A' + priority as u8 - 27) as char,
+        })
+    }
+
+    pub fn total_priority(self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.items().collect::<String>())
+    }
+}
+
+// Splits a line of items into this many compartments of the same size
+pub fn compartments(line_num: usize, line: &str, n: usize) -> Result<Vec<ItemSet>, ParseError> {
+    if n == 0 {
+        return Err(ParseError::new(line_num, 1, "at least one compartment"));
+    }
+    if line.is_empty() {
+        return Err(ParseError::new(line_num, 1, "an item from a-z or A-Z"));
+    }
+    if let Some(col) = line.chars().position(|c| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::new(line_num, col + 1, "an item from a-z or A-Z"));
+    }
+    // Every item is a single byte, so the line can be split at any of them
+    if !line.len().is_multiple_of(n) {
+        return Err(ParseError::new(line_num, line.len() + 1,
+            &format!("items that split into {} compartments of the same size", n)));
+    }
+    let size = line.len() / n;
+    Ok((0..n).map(|i| ItemSet::from_items(&line[i * size..(i + 1) * size])).collect())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub compartments: [ItemSet; 2],
+}
+
+impl Rucksack {
+    pub fn parse(line_num: usize, line: &str) -> Result<Self, ParseError> {
+        let halves = compartments(line_num, line, 2)?;
+        Ok(Rucksack { compartments: [halves[0], halves[1]] })
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.compartments[0].union(self.compartments[1])
+    }
+
+    // Part 1: The items in both compartments
+    pub fn shared_items(&self) -> ItemSet {
+        ItemSet::common(self.compartments.iter().cloned())
+    }
+}
+
+// Part 2: The items every rucksack in a group has
+pub fn badge_items(group: &[Rucksack]) -> ItemSet {
+    ItemSet::common(group.iter().map(Rucksack::items))
+}
+
+// The puzzle promises a single shared item, but any others are counted too
+fn check_shared(items: ItemSet, what: &str, idx: usize) -> u32 {
+    match items.len() {
+        0 => warn!("No items are shared in {} {}", what, idx + 1),
+        1 => trace!("Shared item in {} {}: {}, priority {}", what, idx + 1, items, items.total_priority()),
+        n => info!("{} items are shared in {} {}: {}, priority {}", n, what, idx + 1, items, items.total_priority()),
+    }
+    items.total_priority()
+}
+
+pub struct Input {
+    pub rucksacks: Vec<Rucksack>,
+    pub group_size: usize,
+}
+
+// Reads the rucksacks, a line at a time in parallel, to be split into groups of the given size
+pub fn parse_with_group_size(data: &str, group_size: usize) -> Result<Input, ParseError> {
+    if group_size == 0 {
+        return Err(ParseError::new(1, 1, "groups of at least one elf"));
+    }
+    let lines: Vec<&str> = data.lines().collect();
+    let rucksacks: Vec<Result<Rucksack, ParseError>> = lines.par_iter().enumerate()
+        .map(|(i, line)| Rucksack::parse(i + 1, line))
+        .collect();
+    // Report the first bad line, whichever thread found it
+    let rucksacks = rucksacks.into_iter().collect::<Result<Vec<Rucksack>, ParseError>>()?;
+    if !rucksacks.len().is_multiple_of(group_size) {
+        return Err(ParseError::new(lines.len() + 1, 1,
+            &format!("{} more rucksacks to make up the last group of {}",
+                group_size - rucksacks.len() % group_size, group_size)));
+    }
+    Ok(Input { rucksacks, group_size })
+}
+
+impl Solution for Day03 {
+    type Input = Input;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_with_group_size(data, GROUP_SIZE)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        // Go through all the rucksacks and tally up the priorities
+        let total_priority: u32 = input.rucksacks.par_iter().enumerate()
+            .map(|(i, rucksack)| check_shared(rucksack.shared_items(), "rucksack", i))
+            .sum();
+        Answer::from(total_priority)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        // Go through all the groups of rucksacks and tally up the priorities
+        let total_priority: u32 = input.rucksacks.par_chunks(input.group_size).enumerate()
+            .map(|(i, group)| check_shared(badge_items(group), "group", i))
+            .sum();
+        Answer::from(total_priority)
+    }
+}
+
+// If a group size is given, the elves are split into groups of that size instead of 3
+pub fn run(filename: &str, part: Option<u32>, group_size: Option<usize>) -> error::Result<()> {
+    let data = solution::read_input(filename)?;
+    let input = parse_with_group_size(&data, group_size.unwrap_or(GROUP_SIZE))
+        .map_err(|e| e.in_file(solution::input_name(filename)))?;
+    solution::print_answers(&solution::solve_parsed::<Day03>(&input, part));
+    Ok(())
+}
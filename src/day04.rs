@@ -0,0 +1,219 @@
+// Solution to Day 4 puzzle
+// https://adventofcode.com/2022/day/4
+
+use std::cmp::Reverse;
+use std::fmt;
+
+use itertools::Itertools;
+
+use error::{self, ParseError};
+use interval::Interval;
+use solution::{self, Answer, Solution};
+
+pub struct Day04;
+
+// The section ranges assigned to a pair of elves
+pub type Assignment = (Interval, Interval);
+
+// Parses a range of the form "min-max", which was sliced out of the line
+fn parse_range(line_num: usize, line: &str, range: &str) -> Result<Interval, ParseError> {
+    let col = error::column_of(line, range);
+    let (min, max) = range.split_once('-')
+        .ok_or_else(|| ParseError::new(line_num, col + range.len(), "a - between the ends of a range"))?;
+    // Anything after the end of the range is a separator too many
+    if let Some(pos) = max.find(['-', ',']) {
+        return Err(ParseError::new(line_num, error::column_of(line, max) + pos, "the end of the range"));
+    }
+    let min = error::parse_token::<u32>(line_num, line, min, "a section number")?;
+    let max = error::parse_token::<u32>(line_num, line, max, "a section number")?;
+    if min > max {
+        return Err(ParseError::new(line_num, col, "a range whose start is not after its end"));
+    }
+    Ok(Interval::new(min as i64, max as i64))
+}
+
+// Parses a line of the form "min1-max1,min2-max2"
+pub fn parse_assignments(line_num: usize, line: &str) -> Result<Assignment, ParseError> {
+    let (first, second) = match line.split_once(',') {
+        Some(halves) => halves,
+        None => {
+            // The comma belongs where the first range ends
+            let col = line.match_indices('-').nth(1).map_or(line.len(), |(idx, _)| idx) + 1;
+            return Err(ParseError::new(line_num, col, "two ranges of the form a-b,c-d"));
+        },
+    };
+    Ok((parse_range(line_num, line, first)?, parse_range(line_num, line, second)?))
+}
+
+// Part 1: One assignment fully contains the other
+pub fn is_redundant((first, second): &Assignment) -> bool {
+    first.contains_interval(second) || second.contains_interval(first)
+}
+
+// Part 2: The assignments overlap at all
+pub fn is_overlapping((first, second): &Assignment) -> bool {
+    first.overlaps(second)
+}
+
+impl Solution for Day04 {
+    type Input = Vec<Assignment>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .enumerate()
+            .map(|(i, line)| parse_assignments(i + 1, line))
+            .collect()
+    }
+
+    fn part1(assignments: &Self::Input) -> Answer {
+        // Go through all the pairs and find redundant assignments.
+        let num_redundant = assignments.iter().filter(|a| is_redundant(a)).count();
+        Answer::from(num_redundant)
+    }
+
+    fn part2(assignments: &Self::Input) -> Answer {
+        // Go through all the pairs and find overlapping assignments.
+        let num_overlapping = assignments.iter().filter(|a| is_overlapping(a)).count();
+        Answer::from(num_overlapping)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day04>(filename, part)
+}
+
+// The sections assigned to one elf, and where the assignment is in the file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Assigned {
+    // 1-indexed line of the file
+    pub line: usize,
+    // 1 or 2, for the first or second elf of the pair on the line
+    pub elf: usize,
+    pub sections: Interval,
+}
+
+impl fmt::Display for Assigned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {} elf {} ({})", self.line, self.elf, self.sections)
+    }
+}
+
+// What a sweep over every assignment in the file finds
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Audit {
+    // Runs of sections covered by the same number of elves, from the lowest section assigned
+    // to the highest, including the runs nobody covers
+    pub coverage: Vec<(Interval, usize)>,
+    pub max_coverage: usize,
+    pub uncovered: Vec<Interval>,
+    // Each assignment that fully contains one on another line, with the one it contains
+    pub containments: Vec<(Assigned, Assigned)>,
+}
+
+impl Audit {
+    // The number of elves covering the section
+    pub fn coverage_of(&self, section: i64) -> usize {
+        let idx = self.coverage.partition_point(|(run, _)| run.hi < section);
+        self.coverage.get(idx).filter(|(run, _)| run.contains(section)).map_or(0, |&(_, count)| count)
+    }
+
+    // The runs of sections with the most elves on them
+    pub fn busiest(&self) -> Vec<Interval> {
+        self.coverage.iter()
+            .filter(|&&(_, count)| count == self.max_coverage && count > 0)
+            .map(|&(run, _)| run)
+            .collect()
+    }
+}
+
+pub fn assigned(assignments: &[Assignment]) -> Vec<Assigned> {
+    assignments.iter().enumerate()
+        .flat_map(|(i, &(first, second))| vec![
+            Assigned { line: i + 1, elf: 1, sections: first },
+            Assigned { line: i + 1, elf: 2, sections: second },
+        ])
+        .collect()
+}
+
+// Sweeps over where each assignment starts and ends, counting the elves on each run of sections
+fn sweep_coverage(elves: &[Assigned]) -> Vec<(Interval, usize)> {
+    let mut events: Vec<(i64, i64)> = elves.iter()
+        .flat_map(|elf| vec![(elf.sections.lo, 1), (elf.sections.hi + 1, -1)])
+        .collect();
+    events.sort();
+
+    let mut coverage: Vec<(Interval, usize)> = Vec::new();
+    let mut count = 0;
+    for (x, group) in &events.iter().group_by(|&&(x, _)| x) {
+        // The run so far ends where the count changes
+        let next = count + group.map(|&(_, change)| change).sum::<i64>();
+        if next == count {
+            continue;
+        }
+        if let Some(last) = coverage.last_mut() {
+            last.0.hi = x - 1;
+        }
+        coverage.push((Interval::new(x, x), next as usize));
+        count = next;
+    }
+    // Nobody covers anything after the last assignment ends
+    coverage.pop();
+    coverage
+}
+
+// Pairs where one assignment fully contains another on a different line.
+// Sorted by start, with the longest first, an assignment can only contain the ones after it
+// that start before it ends.
+fn sweep_containments(elves: &[Assigned]) -> Vec<(Assigned, Assigned)> {
+    let mut sorted = elves.to_vec();
+    sorted.sort_by_key(|elf| (elf.sections.lo, Reverse(elf.sections.hi), elf.line, elf.elf));
+
+    let mut containments = Vec::new();
+    for (i, outer) in sorted.iter().enumerate() {
+        for inner in sorted[i + 1..].iter().take_while(|inner| inner.sections.lo <= outer.sections.hi) {
+            if inner.line != outer.line && inner.sections.hi <= outer.sections.hi {
+                containments.push((*outer, *inner));
+            }
+        }
+    }
+    containments.sort_by_key(|(outer, inner)| (outer.line, outer.elf, inner.line, inner.elf));
+    containments
+}
+
+// Sweeps over every assignment in the file, rather than comparing the pairs on each line
+pub fn audit_roster(assignments: &[Assignment]) -> Audit {
+    let elves = assigned(assignments);
+    let coverage = sweep_coverage(&elves);
+    let max_coverage = coverage.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    let uncovered: Vec<Interval> = coverage.iter().filter(|&&(_, count)| count == 0).map(|&(run, _)| run).collect();
+    let containments = sweep_containments(&elves);
+    info!("Swept {} assignments: at most {} elves on a section, {} runs of sections uncovered, {} containments",
+        elves.len(), max_coverage, uncovered.len(), containments.len());
+    Audit { coverage, max_coverage, uncovered, containments }
+}
+
+fn describe_runs(runs: &[Interval]) -> String {
+    if runs.is_empty() {
+        "none".to_string()
+    } else {
+        runs.iter().map(|run| run.to_string()).join(", ")
+    }
+}
+
+// Prints the coverage of each section and the assignments that contain others
+pub fn audit(filename: &str) -> error::Result<()> {
+    let data = solution::read_input(filename)?;
+    let assignments = Day04::parse(&data).map_err(|e| e.in_file(solution::input_name(filename)))?;
+    let audit = audit_roster(&assignments);
+    println!("Sections  Elves");
+    for &(run, count) in &audit.coverage {
+        println!("{:<8}  {:>5}", run.to_string(), count);
+    }
+    println!("\nMost elves on a section: {}, on sections {}", audit.max_coverage, describe_runs(&audit.busiest()));
+    println!("Sections nobody covers: {}", describe_runs(&audit.uncovered));
+    println!("\nAssignments that fully contain one on another line: {}", audit.containments.len());
+    for (outer, inner) in &audit.containments {
+        println!("{} contains {}", outer, inner);
+    }
+    Ok(())
+}
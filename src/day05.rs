@@ -0,0 +1,159 @@
+// Solution to Day 5 puzzle
+// https://adventofcode.com/2022/day/5
+
+use error::{self, ParseError};
+use log;
+use solution::{self, Answer, Solution};
+
+pub struct Day05;
+
+#[derive(Clone)]
+pub struct Instruction {
+    pub num: usize,
+    pub from: usize,
+    pub to: usize
+}
+
+#[derive(Clone)]
+pub struct BoxStacks {
+    pub crates: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>
+}
+
+impl BoxStacks {
+    pub fn new(data: &str) -> Result<Self, ParseError> {
+        // Find where the newline is
+        let data = data.lines().collect::<Vec<&str>>();
+        let pivot_idx = match data.iter().position(|line| line.is_empty()) {
+            Some(idx) if idx > 0 => idx,
+            _ => return Err(ParseError::new(data.len() + 1, 1,
+                "a blank line between the crates and the instructions")),
+        };
+
+        // Get the stacks
+        let mut crates = Vec::new();
+        let labels = data[pivot_idx - 1];
+        let last_label = error::expect(labels.split_whitespace().last(), pivot_idx, 1, "stack numbers")?;
+        let num_crates: usize = error::parse_token(pivot_idx, labels, last_label, "a stack number")?;
+        crates.resize(num_crates, Vec::new());
+
+        for line in data[0..pivot_idx - 1].iter().rev() {
+            for (i, stack) in crates.iter_mut().enumerate() {
+                let idx = 4*i + 1;
+                let chars = line.chars().collect::<Vec<char>>();
+                if line.len() > idx && chars[idx].is_ascii_uppercase() {
+                    stack.push(chars[idx]);
+                }
+            }
+        }
+
+        // Get the instructions, of the form "move <num> from <from> to <to>".
+        // Both cranes leave the stacks the same heights, so each move can be checked
+        // against what the stacks will hold by then.
+        let mut heights: Vec<usize> = crates.iter().map(|stack| stack.len()).collect();
+        let mut instructions = Vec::new();
+        for (i, line) in data[pivot_idx+1..].iter().enumerate() {
+            let line_num = pivot_idx + i + 2;
+            let split_line = line.split_whitespace().collect::<Vec<&str>>();
+            if split_line.len() != 6 || split_line[0] != "move" ||
+               split_line[2] != "from" || split_line[4] != "to" {
+                return Err(ParseError::new(line_num, 1, "an instruction like \"move 1 from 2 to 3\""));
+            }
+            let num: usize = error::parse_token(line_num, line, split_line[1], "a number of crates")?;
+            let stack_idx = |token: &str| {
+                match error::parse_token::<usize>(line_num, line, token, "a stack number")? {
+                    idx if (1..=num_crates).contains(&idx) => Ok(idx),
+                    _ => Err(ParseError::new(line_num, error::column_of(line, token),
+                        &format!("a stack number from 1 to {}", num_crates))),
+                }
+            };
+            let from = stack_idx(split_line[3])?;
+            let to = stack_idx(split_line[5])?;
+            if num > heights[from - 1] {
+                return Err(ParseError::new(line_num, error::column_of(line, split_line[1]),
+                    &format!("at most {} crates, as that is all stack {} has", heights[from - 1], from)));
+            }
+            heights[from - 1] -= num;
+            heights[to - 1] += num;
+            instructions.push(Instruction { num, from, to });
+        }
+
+        Ok(Self { crates, instructions })
+    }
+
+    // The CrateMover 9000 (part 1) moves crates one at a time,
+    // while the CrateMover 9001 (part 2) moves them all at once.
+    pub fn execute(&mut self, move_all_at_once: bool) {
+        self.print_crates();
+
+        for inst in self.instructions.iter() {
+            trace!("Moving {} crates from {} to {}", inst.num, inst.from, inst.to);
+            if move_all_at_once {
+                let split_idx = self.crates[inst.from - 1].len() - inst.num;
+                let mut split_vec = self.crates[inst.from - 1].split_off(split_idx);
+                self.crates[inst.to - 1].append(&mut split_vec);
+            } else {
+                for _ in 0..inst.num {
+                    let item = self.crates[inst.from - 1].pop().unwrap();
+                    self.crates[inst.to - 1].push(item);
+                }
+            }
+            self.print_crates();
+        }
+    }
+
+    pub fn get_last_elements(&self) -> String {
+        self.crates.iter().filter_map(|stack| stack.last()).collect()
+    }
+
+    pub fn print_crates(&self) {
+        if !log::enabled(log::Level::Trace) {
+            return;
+        }
+        let num_crates = self.crates.len();
+        let lengths = self.crates.iter()
+            .map(|x| x.len()).collect::<Vec<usize>>();
+        let max_len = lengths.iter().max().unwrap();
+
+        let mut print_str = String::new();
+        for i in (0..*max_len).rev() {
+            let mut stack_str = String::new();
+            for j in 0..num_crates {
+                if self.crates[j].len() > i {
+                    let item = self.crates[j][i].to_string();
+                    stack_str.push_str(&item);
+                } else {
+                    stack_str.push(' ');
+                }
+            }
+            print_str.push_str(&stack_str);
+            print_str.push('\n');
+        }
+
+        trace!("{}", print_str);
+    }
+}
+
+impl Solution for Day05 {
+    type Input = BoxStacks;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        BoxStacks::new(data)
+    }
+
+    fn part1(box_stacks: &Self::Input) -> Answer {
+        let mut box_stacks = box_stacks.clone();
+        box_stacks.execute(false);
+        Answer::from(box_stacks.get_last_elements())
+    }
+
+    fn part2(box_stacks: &Self::Input) -> Answer {
+        let mut box_stacks = box_stacks.clone();
+        box_stacks.execute(true);
+        Answer::from(box_stacks.get_last_elements())
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day05>(filename, part)
+}
@@ -0,0 +1,50 @@
+// Solution to Day 6 puzzle
+// https://adventofcode.com/2022/day/6
+
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day06;
+
+// Returns the number of characters processed before the first marker, if any
+pub fn get_marker(chars : &[char], streak_length: usize) -> Option<usize> {
+    for i in streak_length..=chars.len() {
+        let set = HashSet::<&char>::from_iter(
+            &chars[i-streak_length..i]
+        );
+        if set.len() == streak_length {
+            info!("Found marker at index {}", i);
+            return Some(i);
+        }
+    }
+    None
+}
+
+impl Solution for Day06 {
+    type Input = Vec<char>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let data = data.trim();
+        match data.chars().position(|c| !c.is_ascii_lowercase()) {
+            Some(col) => Err(ParseError::new(1, col + 1, "a lowercase letter")),
+            None => Ok(data.chars().collect()),
+        }
+    }
+
+    // Part 1: Look for the start-of-packet marker
+    fn part1(chars: &Self::Input) -> Answer {
+        get_marker(chars, 4).map_or(Answer::None, Answer::from)
+    }
+
+    // Part 2: Look for the start-of-message marker
+    fn part2(chars: &Self::Input) -> Answer {
+        get_marker(chars, 14).map_or(Answer::None, Answer::from)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day06>(filename, part)
+}
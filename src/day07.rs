@@ -0,0 +1,105 @@
+// Solution to Day 7 puzzle
+// https://adventofcode.com/2022/day/7
+
+use std::collections::HashMap;
+use std::cmp::min;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day07;
+
+// Parses the terminal output into a map of directory paths to their total size
+pub fn get_folder_sizes(data: &str) -> Result<HashMap<String, u32>, ParseError> {
+    let mut folder_stack: Vec<String> = Vec::new();
+    let mut folder_map = HashMap::<String, u32>::new();
+    folder_map.insert("/".to_string(), 0);
+
+    for (i, line) in data.lines().enumerate() {
+        let line_num = i + 1;
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let elem = error::expect(parts.first(), line_num, 1, "a command or a listing")?;
+
+        if *elem == "$" {
+            // Command case
+            let cmd = *error::expect(parts.get(1), line_num, line.len() + 1, "a command")?;
+            if cmd == "ls" {
+                continue;
+            } else if cmd == "cd" {
+                let dir = error::expect(parts.get(2), line_num, line.len() + 1, "a directory name")?;
+                let mut dir = dir.to_string();
+                if dir == "/" {
+                    dir = "".to_string();
+                }
+                if dir == ".." {
+                    folder_stack.pop();
+                } else {
+                    let mut full_path = folder_stack.last()
+                        .unwrap_or(&"".to_string())
+                        .clone();
+                    full_path.push('/');
+                    full_path.push_str(&dir);
+                    folder_stack.push(full_path);
+                }
+            } else {
+                return Err(ParseError::new(line_num, error::column_of(line, cmd), "ls or cd"));
+            }
+        } else if *elem == "dir" {
+            // Directory case
+            let name = error::expect(parts.get(1), line_num, line.len() + 1, "a directory name")?;
+            let mut full_path = folder_stack.last()
+                        .unwrap_or(&"".to_string())
+                        .clone();
+            full_path.push('/');
+            full_path.push_str(name);
+            folder_map.entry(full_path).or_insert(0);
+        } else {
+            // File case
+            let file_size: u32 = error::parse_token(line_num, line, elem, "a file size or dir")?;
+            for dir in &folder_stack {
+                if let Some(folder_size) = folder_map.get_mut(&dir.clone()) {
+                    *folder_size += file_size;
+                }
+            }
+        }
+    }
+
+    Ok(folder_map)
+}
+
+impl Solution for Day07 {
+    type Input = HashMap<String, u32>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        get_folder_sizes(data)
+    }
+
+    // Part 1: Total size of the small directories
+    fn part1(folder_map: &Self::Input) -> Answer {
+        const MAX_SIZE: u32 = 100000;
+        let size_count: u32 = folder_map.values()
+            .filter(|&&size| size <= MAX_SIZE)
+            .sum();
+        Answer::from(size_count)
+    }
+
+    // Part 2: Delete smallest directory
+    fn part2(folder_map: &Self::Input) -> Answer {
+        const REQUIRED_SIZE: u32 = 70000000 - 30000000;
+        let total_size = folder_map.get("/").unwrap();
+        info!("Total file size: {}", total_size);
+        let mut smallest_dir_size = u32::MAX;
+        for &size in folder_map.values() {
+            if (total_size - size) <= REQUIRED_SIZE {
+                smallest_dir_size = min(smallest_dir_size, size);
+            }
+        }
+        info!("Deleting folder with size {} to get to {}",
+            smallest_dir_size, total_size - smallest_dir_size);
+        Answer::from(smallest_dir_size)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day07>(filename, part)
+}
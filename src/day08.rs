@@ -0,0 +1,100 @@
+// Solution to Day 8 puzzle
+// https://adventofcode.com/2022/day/8
+
+use std::cmp::max;
+
+use error::{self, ParseError};
+use grid::Grid2D;
+use image;
+use solution::{self, Answer, Solution};
+
+pub struct Day08;
+
+// Visibility check function for part 1
+pub fn check_visibility(data: &Grid2D<u32>, row: usize, col: usize) -> bool {
+    if row==0 || row==(data.n_rows()-1) || col==0 || col==(data.n_cols()-1) {
+        // If the tree is in a corner, it is visible
+        true
+    } else {
+        let height = data[(row, col)];
+        let column = data.col(col);
+        let line = data.row(row);
+        // TOP
+        if column.iter().take(row).all(|&h| h < height) {
+            return true;
+        }
+        // BOTTOM
+        if column.iter().skip(row+1).all(|&h| h < height) {
+            return true;
+        }
+        // LEFT
+        if line.iter().take(col).all(|&h| h < height) {
+            return true;
+        }
+        // RIGHT
+        line.iter().skip(col+1).all(|&h| h < height)
+    }
+}
+
+// Number of trees that can be seen from a tree of the given height, looking along a line of trees
+fn viewing_distance<'a, I: Iterator<Item = &'a u32>>(trees: I, height: u32) -> u32 {
+    let mut distance = 0;
+    for &h in trees {
+        distance += 1;
+        if h >= height {
+            break;
+        }
+    }
+    distance
+}
+
+// Scenic score function for Part 2
+pub fn scenic_score(data: &Grid2D<u32>, row: usize, col: usize) -> u32 {
+    info!("Check scenic score for ({}, {}) = {}", row, col, data[(row, col)]);
+    let height = data[(row, col)];
+    let column = data.col(col);
+    let line = data.row(row);
+    let top_scenic_score = viewing_distance(column.iter().take(row).rev(), height);
+    let bottom_scenic_score = viewing_distance(column.iter().skip(row+1), height);
+    let left_scenic_score = viewing_distance(line.iter().take(col).rev(), height);
+    let right_scenic_score = viewing_distance(line.iter().skip(col+1), height);
+
+    info!("Top: {}, Bottom: {}, Left: {}, Right: {}",
+        top_scenic_score, bottom_scenic_score, left_scenic_score, right_scenic_score);
+    top_scenic_score * bottom_scenic_score * left_scenic_score * right_scenic_score
+}
+
+impl Solution for Day08 {
+    type Input = Grid2D<u32>;
+
+    // Convert the data to a grid of tree heights
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        Grid2D::parse(data, "a tree height from 0 to 9", |c| c.to_digit(10))
+    }
+
+    // PART 1: Go through the data and find all visible trees
+    fn part1(data: &Self::Input) -> Answer {
+        if image::is_dumping() {
+            // Taller trees are lighter
+            image::dump_pgm("day08_heights", &data.map(|&h| (h * 255 / 9) as u8), 4);
+        }
+        let visible_trees = data.iter()
+            .filter(|&((i, j), _)| check_visibility(data, i, j))
+            .count();
+        Answer::from(visible_trees)
+    }
+
+    // PART 2: Go through the data and find max scenic score
+    fn part2(data: &Self::Input) -> Answer {
+        let mut max_scenic_score = 0;
+        for ((i, j), _) in data.iter() {
+            let score = scenic_score(data, i, j);
+            max_scenic_score = max(score, max_scenic_score);
+        }
+        Answer::from(max_scenic_score)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day08>(filename, part)
+}
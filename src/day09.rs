@@ -0,0 +1,78 @@
+// Solution to Day 9 puzzle
+// https://adventofcode.com/2022/day/9
+
+use std::collections::HashSet;
+
+use error::{self, ParseError};
+use geometry::{Direction, Point2};
+use solution::{self, Answer, Solution};
+
+pub struct Day09;
+
+pub fn get_tail_position(head: Point2, tail: Point2) -> Point2 {
+    let diff = head - tail;
+    let distance = head.manhattan(tail);
+    // Follow to the left/right or top/bottom,
+    // or move diagonally if the head is far enough away
+    let in_line = diff.x == 0 || diff.y == 0;
+    if (in_line && distance > 1) || distance > 2 {
+        tail + diff.signum()
+    } else {
+        tail
+    }
+}
+
+// Moves the rope and returns the number of positions visited by its tail
+pub fn simulate_rope(moves: &[(Direction, u32)], num_segments: usize) -> usize {
+    // Initialize data structures for all the segments
+    let init_pos = Point2::default();
+    let mut tail_pos_map = HashSet::new();
+    let mut positions = vec![init_pos; num_segments];
+    tail_pos_map.insert(init_pos);
+
+    for (dir, num_steps) in moves {
+        for _ in 0..*num_steps {
+            // Move the head
+            positions[0] = positions[0].step(*dir);
+
+            // Move the rest of the rope in order
+            for i in 1..num_segments {
+                positions[i] = get_tail_position(positions[i-1], positions[i]);
+            }
+            tail_pos_map.insert(*positions.last().unwrap());
+        }
+    }
+
+    tail_pos_map.len()
+}
+
+impl Solution for Day09 {
+    // The direction and number of steps of each move
+    type Input = Vec<(Direction, u32)>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let mut moves = Vec::new();
+        for (i, line) in data.lines().enumerate() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let dir = error::expect(parts.first(), i + 1, 1, "a direction")?;
+            let dir = error::expect(Direction::from_udlr(dir), i + 1, error::column_of(line, dir), "R, L, U, or D")?;
+            let num_steps = error::expect(parts.get(1), i + 1, line.len() + 1, "a number of steps")?;
+            moves.push((dir, error::parse_token(i + 1, line, num_steps, "a number of steps")?));
+        }
+        Ok(moves)
+    }
+
+    // Part 1: The rope has a head and a tail
+    fn part1(moves: &Self::Input) -> Answer {
+        Answer::from(simulate_rope(moves, 2))
+    }
+
+    // Part 2: The rope has 10 segments
+    fn part2(moves: &Self::Input) -> Answer {
+        Answer::from(simulate_rope(moves, 10))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day09>(filename, part)
+}
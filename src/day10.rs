@@ -0,0 +1,97 @@
+// Solution to Day 10 puzzle
+// https://adventofcode.com/2022/day/10
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day10;
+
+pub fn render(rendering: &mut String, sprite_pos: i32, cycle: i32, render_period: i32) {
+    let pixel_pos = (cycle-1) % render_period;
+
+    // Add line break if the render period was hit
+    if pixel_pos == 0 {
+        rendering.push('\n');
+    }
+
+    // If the sprite position coincides with the pixel
+    if (pixel_pos >= sprite_pos - 1) && (pixel_pos <= sprite_pos + 1) {
+        rendering.push('#');
+    } else {
+        rendering.push('.');
+    }
+}
+
+// Runs the program, returning the total signal strength and the CRT rendering
+pub fn execute(program: &[Option<i32>]) -> (i32, String) {
+    // Constants
+    const MEASURE_START: i32 = 20;
+    const MEASURE_PERIOD: i32 = 40;
+    const RENDER_STEP: i32 = 40;
+
+    // Initial state
+    let mut x: i32 = 1;
+    let mut cycle: i32 = 1;
+    let mut next_cycle: i32 = MEASURE_START;
+    let mut signal_strength: i32 = 0;
+    let mut rendering = "".to_string();
+
+    // Loop through the instructions
+    for instruction in program {
+        if let Some(val) = *instruction {  // addx
+            for i in 0..2 {
+                render(&mut rendering, x, cycle, RENDER_STEP);
+                cycle += 1;
+                if i == 1 {
+                    x += val;
+                }
+                if cycle == next_cycle {
+                    signal_strength += x * next_cycle;
+                    next_cycle += MEASURE_PERIOD;
+                }
+            }
+        } else {  // noop
+            render(&mut rendering, x, cycle, RENDER_STEP);
+            cycle += 1;
+            if cycle == next_cycle {
+                signal_strength += x * next_cycle;
+                next_cycle += MEASURE_PERIOD;
+            }
+        }
+    }
+
+    // Drop the line break before the first row
+    (signal_strength, rendering.trim_start().to_string())
+}
+
+impl Solution for Day10 {
+    // The value added by each instruction, or None for a noop
+    type Input = Vec<Option<i32>>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let mut program = Vec::new();
+        for (i, line) in data.lines().enumerate() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts[..] {
+                ["noop"] => program.push(None),
+                ["addx", val] => program.push(Some(error::parse_token(i + 1, line, val, "a value to add")?)),
+                _ => return Err(ParseError::new(i + 1, 1, "noop or addx <value>")),
+            }
+        }
+        Ok(program)
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        let (signal_strength, _) = execute(program);
+        Answer::from(signal_strength)
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        let (_, rendering) = execute(program);
+        Answer::Art(rendering)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day10>(filename, part)
+}
@@ -0,0 +1,187 @@
+// Solution to Day 11 puzzle
+// https://adventofcode.com/2022/day/11
+
+use evalexpr::*;
+use num::integer::lcm;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day11;
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub id: i64,
+    pub items: Vec<i64>,
+    pub operation: String,
+    pub test_divisible: i64,
+    pub test_true_target: usize,
+    pub test_false_target: usize,
+    pub num_inspections: i64,
+}
+
+impl Monkey {
+    // Parses the block of lines for one monkey, starting at line number first_line
+    pub fn new(data: &str, first_line: usize) -> Result<Self, ParseError> {
+        let lines = data.lines().collect::<Vec<&str>>();
+        if lines.len() < 6 {
+            return Err(ParseError::new(first_line + lines.len(), 1, "6 lines per monkey"));
+        }
+
+        // Gets the text after a prefix on one of the lines
+        let get_value = |idx: usize, prefix: &str| {
+            let line = lines[idx].trim_start();
+            line.strip_prefix(prefix).ok_or_else(|| {
+                ParseError::new(first_line + idx, error::column_of(lines[idx], line),
+                    &format!("\"{}\"", prefix))
+            })
+        };
+
+        // Line 0 is the ID
+        let id_text = get_value(0, "Monkey ")?.trim_end_matches(':');
+        let id = error::parse_token(first_line, lines[0], id_text, "a monkey ID")?;
+
+        // Line 1 is the starting items
+        let mut items = Vec::new();
+        for item in get_value(1, "Starting items: ")?.split(", ") {
+            items.push(error::parse_token(first_line + 1, lines[1], item, "a worry level")?);
+        }
+
+        // Line 2 is the operation
+        let operation = get_value(2, "Operation: new = ")?.to_string();
+        if build_operator_tree(&operation).is_err() {
+            return Err(ParseError::new(first_line + 2, lines[2].len() - operation.len() + 1,
+                "an arithmetic expression of old"));
+        }
+
+        // Lines 3, 4, and 5 are the test values
+        let test_divisible = error::parse_token(first_line + 3, lines[3],
+            get_value(3, "Test: divisible by ")?, "a divisor")?;
+        let test_true_target = error::parse_token(first_line + 4, lines[4],
+            get_value(4, "If true: throw to monkey ")?, "a monkey ID")?;
+        let test_false_target = error::parse_token(first_line + 5, lines[5],
+            get_value(5, "If false: throw to monkey ")?, "a monkey ID")?;
+
+        Ok(Self { id, items, operation, test_divisible, test_true_target, test_false_target, num_inspections: 0 })
+    }
+
+    pub fn operate(&mut self, item_idx: usize) {
+        let mut context = context_map!{"old" => self.items[item_idx]}.unwrap();
+        self.items[item_idx] = 
+            eval_int_with_context_mut(&self.operation[..], &mut context).unwrap();
+    }
+
+    pub fn print(&self) {
+        trace!("Monkey: {}, Items: {:?}, Inspections: {}", self.id, self.items, self.num_inspections);
+    }
+}
+
+
+pub fn initialize_monkeys(data: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut first_lines = Vec::new();
+    let mut line_num = 1;
+    for monkey_text in data.split("\n\n") {
+        monkeys.push(Monkey::new(monkey_text, line_num)?);
+        first_lines.push(line_num);
+        line_num += monkey_text.lines().count() + 1;
+    }
+
+    // Make sure the monkeys only throw to each other
+    for (i, monkey) in monkeys.iter().enumerate() {
+        if monkey.test_true_target >= monkeys.len() || monkey.test_false_target >= monkeys.len() {
+            return Err(ParseError::new(first_lines[i] + 4, 1,
+                &format!("monkeys to throw to from 0 to {}", monkeys.len() - 1)));
+        }
+    }
+    Ok(monkeys)
+}
+
+pub fn simulate_monkeys(monkeys: &mut [Monkey], rounds: usize, worry_divided: bool) {
+    trace!("=== INITIAL ===");
+    for monkey in monkeys.iter() {
+        monkey.print();
+    }
+
+    let mut div_lcm = 1;
+    for monkey in monkeys.iter() {
+        div_lcm = lcm(div_lcm, monkey.test_divisible);
+    }
+    trace!("Lowest common multiple: {}", div_lcm);
+
+    for round in 0..rounds {
+        trace!("=== ROUND {} ===", round + 1);
+        // Inspections
+        for m in 0..monkeys.len() {
+            for i in (0..monkeys[m].items.len()).rev() {
+                trace!("  Monkey {} inspecting an item with worry level {}", m, monkeys[m].items[i]);
+                // Operate on worry levels
+                monkeys[m].operate(i);
+
+                // Boredom
+                if worry_divided {
+                    monkeys[m].items[i] /= 3;
+                    trace!("    Worry level is now {}", monkeys[m].items[i]);
+                }
+
+                // Update inspection count
+                monkeys[m].num_inspections += 1;
+
+                let mut item_val = monkeys[m].items[i];
+                let is_divisible = 
+                    (item_val % monkeys[m].test_divisible) == 0;
+                let throw_target = match is_divisible {
+                    true => monkeys[m].test_true_target,
+                    false => monkeys[m].test_false_target,
+                };
+
+                // Bound the items to prevent overflow
+                item_val %= div_lcm;
+
+                // Do the actual throwing
+                monkeys[throw_target].items.push(item_val);
+                monkeys[m].items.remove(i);
+                trace!("    Worry level {} divisible by {}: {}\n    Throwing to monkey {}",
+                    item_val, monkeys[m].test_divisible, is_divisible, throw_target);
+            }
+        }
+        for monkey in monkeys.iter() {
+            monkey.print();
+        }
+    }
+}
+
+pub fn get_monkey_business(monkeys: &[Monkey]) -> i64 {
+    let mut inspections_vec = monkeys.iter()
+        .map(|x| x.num_inspections)
+        .collect::<Vec<i64>>();
+    inspections_vec.sort();
+    inspections_vec.reverse();
+    inspections_vec[0] * inspections_vec[1]
+}
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        initialize_monkeys(data)
+    }
+
+    // Part 1: Worry levels are divided by 3 after each inspection
+    fn part1(monkeys: &Self::Input) -> Answer {
+        let mut monkeys = monkeys.clone();
+        simulate_monkeys(&mut monkeys, 20, true);
+        Answer::from(get_monkey_business(&monkeys))
+    }
+
+    // Part 2: Worry levels are no longer divided
+    fn part2(monkeys: &Self::Input) -> Answer {
+        let mut monkeys = monkeys.clone();
+        simulate_monkeys(&mut monkeys, 10000, false);
+        Answer::from(get_monkey_business(&monkeys))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day11>(filename, part)
+}
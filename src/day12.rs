@@ -0,0 +1,98 @@
+// Solution to Day 12 puzzle
+// https://adventofcode.com/2022/day/12
+
+use error::{self, ParseError};
+use grid::Grid2D;
+use image::{self, Rgb};
+use search::{self, Path};
+use solution::{self, Answer, Solution};
+
+pub struct Day12;
+
+// Helper functions
+pub fn height_value(val: char) -> u8 {
+    match val {
+        'S' => b'a',
+        'E' => b'z',
+        _ => val as u8,
+    }
+}
+
+// Colours of the heightmap, from dark low ground to light high ground, with the route in red
+pub fn colour(val: char) -> Rgb {
+    match val {
+        'S' | 'E' => [255, 255, 255],
+        'a'..='z' => {
+            let level = (val as u8 - b'a') * 8;
+            [level / 2, 40 + level, level / 2]
+        },
+        _ => [220, 40, 40],
+    }
+}
+
+// Finds the shortest path up to E, where each step can climb at most one height.
+// Positions are (row, col) on the grid.
+pub fn search(data: &Grid2D<char>, maybe_start_pos: Option<(usize, usize)>) -> Option<Path<(usize, usize), usize>> {
+    // If not specified the starting pose
+    let start_pos = maybe_start_pos
+        .or_else(|| data.find(|&val| val == 'S'))
+        .unwrap_or((0, 0));
+
+    let neighbours = |&(row, col): &(usize, usize)| {
+        let max_height = height_value(data[(row, col)]) + 1;
+        data.neighbours4(row, col).filter(move |&pos| height_value(data[pos]) <= max_height)
+    };
+    search::bfs(start_pos, neighbours, |&pos| data[pos] == 'E')
+}
+
+impl Solution for Day12 {
+    type Input = Grid2D<char>;
+
+    // Convert the data to a grid of heights
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid2D::parse(data, "a height from a-z, S, or E", |c| match c {
+            'a'..='z' | 'S' | 'E' => Some(c),
+            _ => None,
+        })?;
+        let num_start = grid.iter().filter(|&(_, &c)| c == 'S').count();
+        let num_end = grid.iter().filter(|&(_, &c)| c == 'E').count();
+        if num_start != 1 || num_end != 1 {
+            return Err(ParseError::new(grid.n_rows() + 1, 1, "exactly one S and one E"));
+        }
+        Ok(grid)
+    }
+
+    // PART 1: Search from S.
+    fn part1(data: &Self::Input) -> Answer {
+        match search(data, None) {
+            Some(path) => {
+                if image::is_dumping() {
+                    let mut route = data.clone();
+                    for &pos in &path.nodes[1..path.nodes.len() - 1] {
+                        route[pos] = '*';
+                    }
+                    image::dump_ppm("day12_route", &route.map(|&val| colour(val)), 4);
+                }
+                Answer::from(path.cost)
+            },
+            None => {
+                warn!("No path to E found");
+                Answer::None
+            },
+        }
+    }
+
+    // PART 2: Search from all 'a' positions.
+    fn part2(data: &Self::Input) -> Answer {
+        let min_n_steps = data.iter()
+            .filter(|&(_, &val)| val == 'a')
+            .filter_map(|(pos, _)| search(data, Some(pos)))
+            .map(|path| path.cost)
+            .min();
+        min_n_steps.map_or(Answer::None, Answer::from)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day12>(filename, part)
+}
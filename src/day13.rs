@@ -0,0 +1,192 @@
+// Solution to Day 13 puzzle
+// https://adventofcode.com/2022/day/13
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day13;
+
+pub fn compare(left: &str, right: &str) -> i32 {
+    trace!("Comparing {} and {}", left, right);
+
+    // Base case: If left or right are empty
+    if left.is_empty() && right.is_empty() {
+        return 0;
+    } else if left.is_empty() && !right.is_empty() {
+        return 1;
+    } else if !left.is_empty() && right.is_empty() {
+        return -1;
+    }
+
+    // Base case: If both values are integers
+    if let (Ok(left_num), Ok(right_num)) = (left.parse::<u32>(), right.parse::<u32>()) {
+        if left_num < right_num {
+            return 1;
+        } else if left_num > right_num {
+            return -1;
+        } else {
+            return 0;
+        }
+    }
+
+    // Now strip brackets and keep going recursively
+    let left_has_brackets = left.starts_with('[') &&
+                            left.chars().nth(left.len() - 1).unwrap() == ']';
+    let right_has_brackets = right.starts_with('[') &&
+                             right.chars().nth(right.len() - 1).unwrap() == ']';
+    let mut new_left = left;
+    if left_has_brackets {
+        new_left = &left[1..left.len()-1];
+    }
+    let mut new_right = right;
+    if right_has_brackets {
+        new_right = &right[1..right.len()-1];
+    }
+
+    // At this point, no entities have brackets, so we'll loop through them.
+    let mut index = 0;
+    let left_parts = split(new_left);
+    let right_parts = split(new_right);
+    loop {
+        let left_done = left_parts.len() < index + 1;
+        let right_done = right_parts.len() < index + 1;
+        if left_done && right_done {
+            trace!("Both ran out of items");
+            return 0;
+        } else if left_done && !right_done {
+            trace!("Left ran out of items");
+            return 1;
+        } else if !left_done && right_done {
+            trace!("Right ran out of items");
+            return -1;
+        } else{
+            let result = compare(left_parts[index], right_parts[index]);
+            if result != 0 {
+                return result;
+            }
+        }
+        index += 1;
+    }
+}
+
+pub fn split(input: &str) -> Vec<&str> {
+    let mut output: Vec<&str> = Vec::new();
+    let mut start_idx = 0;
+    let mut opening_brackets = 0;
+
+    for i in 0..input.len() {
+        match input.chars().nth(i) {
+            Some('[') => opening_brackets += 1,
+            Some(']') => opening_brackets -= 1,
+            Some(',')
+                if opening_brackets == 0 => {
+                    output.push(&input[start_idx..i]);
+                    start_idx = i+1;
+                },
+            _ => {},
+        }
+    }
+    output.push(&input[start_idx..]);
+    output
+}
+
+// Part 2: Add divider packets and then sort by comparison function
+pub fn get_decoder_key(packets: &[String]) -> usize {
+    let divider_packet_1 = "[[2]]";
+    let divider_packet_2 = "[[6]]";
+    let mut all_packets = Vec::from([divider_packet_1, divider_packet_2]);
+    all_packets.extend(packets.iter().map(|packet| packet.as_str()));
+    // Sort the packets using the comparison function and bubble sort (lol).
+    // Implementation taken from https://www.hackertouch.com/bubble-sort-in-rust.html
+    for i in 0..all_packets.len() {
+        for j in 0..all_packets.len() - 1 - i {
+            if compare(all_packets[j], all_packets[j + 1]) == -1 {
+                all_packets.swap(j, j + 1);
+            }
+        }
+    }
+    trace!("Sorted packets:\n{}", all_packets.join("\n"));
+
+    // Find the indices for the divider packets
+    let mut divider_idx_1: usize = 0;
+    let mut divider_idx_2: usize = 0;
+    for (i, packet) in all_packets.iter().enumerate() {
+        if packet == &divider_packet_1 {
+            divider_idx_1 = i + 1;
+        }
+        if packet == &divider_packet_2 {
+            divider_idx_2 = i + 1;
+        }
+    }
+
+    divider_idx_1 * divider_idx_2
+}
+
+// Checks that a packet is a well-formed list of integers and lists
+pub fn check_packet(line_num: usize, line: &str) -> Result<(), ParseError> {
+    if !line.starts_with('[') {
+        return Err(ParseError::new(line_num, 1, "a packet starting with ["));
+    }
+    let mut depth = 0;
+    for (i, c) in line.chars().enumerate() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            ',' | '0'..='9' if depth > 0 => (),
+            _ => return Err(ParseError::new(line_num, i + 1, "a digit, comma, or bracket inside a list")),
+        }
+        if depth == 0 && i + 1 < line.len() {
+            return Err(ParseError::new(line_num, i + 2, "the end of the packet"));
+        }
+    }
+    if depth > 0 {
+        return Err(ParseError::new(line_num, line.len() + 1, "a closing ]"));
+    }
+    Ok(())
+}
+
+impl Solution for Day13 {
+    // All the packets in order, so each pair is two consecutive packets
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let mut packets = Vec::new();
+        for (i, line) in data.lines().enumerate() {
+            // Every third line separates the pairs
+            if i % 3 == 2 {
+                if !line.is_empty() {
+                    return Err(ParseError::new(i + 1, 1, "a blank line between pairs"));
+                }
+                continue;
+            }
+            check_packet(i + 1, line)?;
+            packets.push(line.to_string());
+        }
+        if packets.len() % 2 != 0 {
+            return Err(ParseError::new(data.lines().count() + 1, 1, "the second packet of the pair"));
+        }
+        Ok(packets)
+    }
+
+    // Part 1: Find the sum of indices with pairs in the right order.
+    fn part1(packets: &Self::Input) -> Answer {
+        let mut index_sum = 0;
+        for (index, pair) in packets.chunks(2).enumerate() {
+            let result = compare(&pair[0], &pair[1]);
+            trace!("Pair {} result {}\n", index + 1, result);
+            if result == 1 {
+                index_sum += index + 1;
+            }
+        }
+        Answer::from(index_sum)
+    }
+
+    // Part 2: Sort all packets and find the decoder key
+    fn part2(packets: &Self::Input) -> Answer {
+        Answer::from(get_decoder_key(packets))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day13>(filename, part)
+}
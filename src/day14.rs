@@ -0,0 +1,218 @@
+// Solution to Day 14 puzzle
+// https://adventofcode.com/2022/day/14
+
+use std::cmp::{min, max};
+use std::fmt;
+
+use ndarray::s;
+
+use animate;
+use error::{self, ParseError};
+use grid::Grid2D;
+use image::{self, Rgb};
+use solution::{self, Answer, Solution};
+
+pub struct Day14;
+
+const GRID_OFFSET: usize = 1000;
+const GRID_SIZE: usize = 2000;
+const SAND_ORIGIN_X: usize = 500;
+const SAND_ORIGIN_Y: usize = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Cell {
+    pub fn colour(&self) -> Rgb {
+        match self {
+            Cell::Air => [20, 20, 30],
+            Cell::Rock => [120, 110, 100],
+            Cell::Sand => [230, 200, 100],
+        }
+    }
+}
+
+// A rock path is a list of (x, y) points connected by straight lines
+pub type RockPath = Vec<(usize, usize)>;
+
+pub fn parse_rocks(data: &str) -> Result<Vec<RockPath>, ParseError> {
+    let mut rocks = Vec::new();
+    for (i, line) in data.lines().enumerate() {
+        let line_num = i + 1;
+        let mut path: RockPath = Vec::new();
+        for x in line.split(" -> ") {
+            let coords = x.split(',').collect::<Vec<&str>>();
+            if coords.len() != 2 {
+                return Err(ParseError::new(line_num, error::column_of(line, x), "a point of the form x,y"));
+            }
+            let pt = (error::parse_token(line_num, line, coords[0], "an x coordinate")?,
+                      error::parse_token(line_num, line, coords[1], "a y coordinate")?);
+
+            // The points must fit in the grid and form straight lines
+            if pt.0 + GRID_OFFSET >= GRID_SIZE || pt.1 >= GRID_SIZE {
+                return Err(ParseError::new(line_num, error::column_of(line, x),
+                    &format!("a point within {},{}", GRID_SIZE - GRID_OFFSET - 1, GRID_SIZE - 1)));
+            }
+            if let Some(&prev) = path.last() {
+                if prev.0 != pt.0 && prev.1 != pt.1 {
+                    return Err(ParseError::new(line_num, error::column_of(line, x),
+                        "a point in a straight line from the previous one"));
+                }
+            }
+            path.push(pt);
+        }
+        rocks.push(path);
+    }
+    Ok(rocks)
+}
+
+pub fn sand_sim(rocks: &[RockPath], add_floor: bool) -> u32 {
+    // Initialize the grid
+    let mut grid = Grid2D::new(GRID_SIZE, GRID_SIZE, Cell::Air);
+
+    // Fill in rocks in the grid
+    let mut floor_height = 0;
+    for pts in rocks {
+        for i in 1..pts.len() {
+            let start = &pts[i-1];
+            let end = &pts[i];
+            let x_start = GRID_OFFSET + min(start.0, end.0);
+            let y_start = min(start.1, end.1);
+            let x_end = GRID_OFFSET + max(start.0, end.0);
+            let y_end = max(start.1, end.1);
+            grid.cells.slice_mut(s![y_start..=y_end, x_start..=x_end]).fill(Cell::Rock);
+            floor_height = max(floor_height, y_end + 2);
+        }
+    }
+    
+    // The part of the cave with rocks in it, and the sand that piles up around them,
+    // which spreads out from the origin as far as it falls when there is a floor
+    let (_, mut view_cols) = grid.bounding_box(|&c| c == Cell::Rock).unwrap_or((0..0, 0..0));
+    if add_floor {
+        let origin = GRID_OFFSET + SAND_ORIGIN_X;
+        view_cols = min(view_cols.start, origin + 1 - floor_height)..max(view_cols.end, origin + floor_height);
+    }
+    let view_rows = 0..floor_height + 1;
+
+    // Add floor, if enabled
+    if add_floor {
+        info!("Added floor at height {}", floor_height);
+        grid.cells.slice_mut(s![floor_height, ..]).fill(Cell::Rock);
+    }
+    
+    // Simulate sand
+    let mut sim_done = false;
+    let mut num_grains_at_rest = 0;
+    while !sim_done {
+        let mut grain_done = false;
+        let mut grain_pos = (SAND_ORIGIN_Y, GRID_OFFSET + SAND_ORIGIN_X);
+        while !grain_done {
+            let down_pos = (grain_pos.0 + 1, grain_pos.1);
+            match grid.get(down_pos.0, down_pos.1) {
+                Some(val) => {
+                    if *val == Cell::Air {
+                        grain_pos = down_pos;
+                        continue;
+                    }
+                },
+                None => {
+                    info!("Grain overflowed on bottom");
+                    sim_done = true;
+                    break;
+                }
+            }
+
+            let left_pos = (grain_pos.0 + 1, grain_pos.1.wrapping_sub(1));
+            match grid.get(left_pos.0, left_pos.1) {
+                Some(val) => {
+                    if *val == Cell::Air {
+                        grain_pos = left_pos;
+                        continue;
+                    }
+                },
+                None => {
+                    info!("Grain overflowed on left");
+                    sim_done = true;
+                    break;
+                }
+            }
+
+            let right_pos = (grain_pos.0 + 1, grain_pos.1 + 1);
+            match grid.get(right_pos.0, right_pos.1) {
+                Some(val) => {
+                    if *val == Cell::Air {
+                        grain_pos = right_pos;
+                        continue;
+                    }
+                },
+                None => {
+                    info!("Grain overflows on right");
+                    sim_done = true;
+                    break;
+                }
+            }
+
+            
+            grid[grain_pos] = Cell::Sand;
+            grain_done = true;
+            num_grains_at_rest += 1;
+            animate::frame(|| format!("Grains at rest: {}\n{}", num_grains_at_rest,
+                grid.crop(view_rows.clone(), view_cols.clone())));
+
+            // Check if we overfilled on top
+            if grain_pos == (SAND_ORIGIN_Y, GRID_OFFSET + SAND_ORIGIN_X) {
+                info!("Overfilled with sand!");
+                sim_done = true;
+            }
+        }
+    }
+
+    let view = grid.crop(view_rows, view_cols);
+    if image::is_dumping() {
+        let name = if add_floor { "day14_cave_with_floor" } else { "day14_cave" };
+        image::dump_ppm(name, &view.map(Cell::colour), 2);
+    }
+    if animate::is_enabled() {
+        animate::last_frame(|| format!("Grains at rest: {}\n{}", num_grains_at_rest, view));
+    } else if view.n_cols() > 0 {
+        info!("\n{}", view);
+    }
+    num_grains_at_rest
+}
+
+impl Solution for Day14 {
+    type Input = Vec<RockPath>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_rocks(data)
+    }
+
+    // Part 1: Sand falls into the abyss
+    fn part1(rocks: &Self::Input) -> Answer {
+        Answer::from(sand_sim(rocks, false))
+    }
+
+    // Part 2: Sand lands on the floor
+    fn part2(rocks: &Self::Input) -> Answer {
+        Answer::from(sand_sim(rocks, true))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day14>(filename, part)
+}
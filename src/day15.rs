@@ -0,0 +1,180 @@
+// Solution to Day 15 puzzle
+// https://adventofcode.com/2022/day/15
+
+use std::cmp::max;
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use error::{self, ParseError};
+use interval::{Interval, IntervalSet};
+use solution::{self, Answer, Solution};
+
+pub struct Day15;
+
+// A sensor position and the position of its closest beacon
+pub type Reading = ((i32, i32), (i32, i32));
+
+// Helper functions
+pub fn distance(sensor: &(i32, i32), beacon: &(i32, i32)) -> u32 {
+    ((sensor.0 - beacon.0).abs() + (sensor.1 - beacon.1).abs()).try_into().unwrap()
+}
+
+pub fn in_range(pt: &(i32, i32), max_dim: i32) -> bool {
+    pt.0 >= 0 && pt.0 <= max_dim && pt.1 >=0 && pt.1 <= max_dim
+}
+
+// The columns of a row that are within range of any sensor
+pub fn row_coverage(readings: &[Reading], test_y: i32) -> IntervalSet {
+    readings.iter()
+        .filter_map(|(sensor, beacon)| {
+            // How far the sensor's range reaches either side of its column on this row
+            let reach = distance(sensor, beacon) as i64 - (sensor.1 - test_y).abs() as i64;
+            if reach >= 0 {
+                Some(Interval::new(sensor.0 as i64 - reach, sensor.0 as i64 + reach))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Part 1: Every column in range of a sensor cannot have a beacon, unless there is one there
+pub fn search_row(readings: &[Reading], test_y: i32) -> u32 {
+    let coverage = row_coverage(readings, test_y);
+    let beacons_in_row: HashSet<i32> = readings.iter()
+        .map(|&(_, beacon)| beacon)
+        .filter(|&(x, y)| y == test_y && coverage.contains(x as i64))
+        .map(|(x, _)| x)
+        .collect();
+    (coverage.len() - beacons_in_row.len() as i64) as u32
+}
+
+// Part 2 refactored implementation
+pub fn search_beacons(readings: &[Reading], max_dim: i32) -> ((i32, i32), i64) {
+    let mut beacon_position: (i32, i32) = (-1, -1);
+    let mut tuning_frequency: i64 = 0;
+
+    for &(sensor, beacon) in readings {
+        // Get a list of points immediately surrounding the radius.
+        // This works because the beacon must be 1 unit away from a sensor's range.
+        let search_radius = (distance(&sensor, &beacon) as i32) + 1;
+        let mut test_pts = Vec::new();
+        for y in -search_radius..=search_radius {
+            let x_offset = search_radius - y.abs();
+
+            let left_pt = (sensor.0 - x_offset, sensor.1 + y);
+            if in_range(&left_pt, max_dim) {
+                test_pts.push(left_pt);
+            }
+
+            // No need to add a right point if we're at the tip of the diamond
+            if x_offset > 0 {
+                let right_pt = (sensor.0 + x_offset, sensor.1 + y);
+                if in_range(&right_pt, max_dim) {
+                    test_pts.push(right_pt);
+                }
+            }
+        }
+
+        // Check this sensor's test points against the other sensors
+        for pt in &test_pts {
+            let mut is_beacon = true;
+            for &(other_sensor, other_beacon) in readings {
+                if other_sensor == sensor {
+                    continue;
+                }
+                // If the point is in range of the sensor, this is not the beacon.
+                let radius = distance(&other_sensor, &other_beacon);
+                if distance(&other_sensor, pt) <= radius {
+                    is_beacon = false;
+                    break;
+                }
+            }
+
+            if is_beacon {
+                beacon_position = *pt;
+                tuning_frequency = (pt.0 as i64) * 4000000 + (pt.1 as i64);
+                return (beacon_position, tuning_frequency);
+            }
+        }
+    }
+
+    // fallback return
+    warn!("No beacon found, returning default values");
+    (beacon_position, tuning_frequency)
+}
+
+pub fn parse_readings(data: &str) -> Result<Vec<Reading>, ParseError> {
+    let mut readings = Vec::new();
+    for (i, line) in data.lines().enumerate() {
+        let line_num = i + 1;
+        // Pretty brute-force split, but it works for this problem
+        let tokens = line.split(&['=', ':', ','][..]).collect::<Vec<&str>>();
+        if tokens.len() != 8 {
+            return Err(ParseError::new(line_num, 1,
+                "a line like \"Sensor at x=2, y=18: closest beacon is at x=-2, y=15\""));
+        }
+        let sensor_x = error::parse_token(line_num, line, tokens[1], "a sensor x coordinate")?;
+        let sensor_y = error::parse_token(line_num, line, tokens[3], "a sensor y coordinate")?;
+        let beacon_x = error::parse_token(line_num, line, tokens[5], "a beacon x coordinate")?;
+        let beacon_y = error::parse_token(line_num, line, tokens[7], "a beacon y coordinate")?;
+
+        readings.push(((sensor_x, sensor_y), (beacon_x, beacon_y)));
+    }
+    Ok(readings)
+}
+
+pub struct Input {
+    pub readings: Vec<Reading>,
+    // The row to check in Part 1
+    pub test_row: i32,
+    // The upper limit of the search range 0..=max_dim in Part 2
+    pub max_dim: i32,
+}
+
+impl Solution for Day15 {
+    type Input = Input;
+
+    // The example and the real puzzle use different rows and ranges,
+    // so tell them apart by the magnitude of the coordinates.
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let readings = parse_readings(data)?;
+        let is_example = readings.iter()
+            .all(|&((sx, sy), (bx, by))| max(max(sx.abs(), sy.abs()), max(bx.abs(), by.abs())) < 1000);
+        if is_example {
+            Ok(Input { readings, test_row: 10, max_dim: 20 })
+        } else {
+            Ok(Input { readings, test_row: 2000000, max_dim: 4000000 })
+        }
+    }
+
+    // PART 1
+    fn part1(input: &Self::Input) -> Answer {
+        Answer::from(search_row(&input.readings, input.test_row))
+    }
+
+    // PART 2
+    fn part2(input: &Self::Input) -> Answer {
+        let (beacon_location, tuning_frequency) = search_beacons(&input.readings, input.max_dim);
+        info!("Beacon at {:?}", beacon_location);
+        Answer::from(tuning_frequency)
+    }
+}
+
+// If a test value is given, it overrides both the row to check and the search limit
+pub fn parse_with_test_val(data: &str, test_val: Option<i32>) -> Result<Input, ParseError> {
+    let mut input = Day15::parse(data)?;
+    if let Some(val) = test_val {
+        input.test_row = val;
+        input.max_dim = val;
+    }
+    Ok(input)
+}
+
+pub fn run(filename: &str, part: Option<u32>, test_val: Option<i32>) -> error::Result<()> {
+    let data = solution::read_input(filename)?;
+    let input = parse_with_test_val(&data, test_val)
+        .map_err(|e| e.in_file(solution::input_name(filename)))?;
+    solution::print_answers(&solution::solve_parsed::<Day15>(&input, part));
+    Ok(())
+}
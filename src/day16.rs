@@ -0,0 +1,258 @@
+// Solution to Day 16 puzzle
+// https://adventofcode.com/2022/day/16
+
+use std::collections::{HashMap, HashSet};
+
+use regex::Regex;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day16;
+
+// The flow rate and the tunnels out of each valve
+pub struct ValveData {
+    pub flow: HashMap<String, u32>,
+    pub transitions: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub step: usize,
+    pub location: String,
+    pub prev_location: String,
+    pub elephant_location: String,
+    pub prev_elephant_location: String,
+    pub valves_open: HashSet<String>,
+    pub score: u32,
+}
+
+pub fn get_flow_from_valves(valve_data: &HashMap<String, u32>,
+                        valves_open: &HashSet<String>) -> u32 {
+    let mut flow: u32 = 0;
+    for valve in valves_open.iter() {
+        flow += valve_data.get(valve).unwrap();
+    }
+    flow
+}
+
+pub fn max_flow_from_valves(valve_data: &HashMap<String, u32>) -> u32 {
+    let mut max_flow: u32 = 0;
+    for flow in valve_data.values() {
+        max_flow += flow;
+    }
+    max_flow
+}
+
+pub fn search_puzzle(valve_flow_data: &HashMap<String, u32>,
+    valve_transition_data: &HashMap<String, String>,
+    max_steps: usize,
+    use_elephant: bool) -> u32 {
+
+    // Hack to add an open
+    let mut valve_transition_mod = HashMap::new();
+    for (name, trans) in valve_transition_data {
+        let mut mod_trans = trans.clone();
+        mod_trans.push_str(", OPEN");
+        valve_transition_mod.insert(name, mod_trans);
+    }
+
+    // Get number of useful valves
+    let mut num_useful_valves = 0;
+    for flow in valve_flow_data.values() {
+        if flow > &0 {
+            num_useful_valves += 1;
+        }
+    }
+    let max_valve_flow = max_flow_from_valves(valve_flow_data);
+
+    // Initialize
+    let mut max_score = 0;
+    let init_state = Node{
+        step: 0, 
+        prev_location: "AA".to_string(),
+        location: "AA".to_string(),
+        prev_elephant_location: "AA".to_string(),
+        elephant_location: "AA".to_string(),
+        valves_open: HashSet::new(),
+        score: 0
+    };
+    let mut state_stack = Vec::new();
+    state_stack.push(init_state);
+
+    while let Some(state) = state_stack.pop() {
+        // If this is max step, don't add more to the stack
+        if state.step >= max_steps {
+            continue;
+        }
+
+        // Update the max running score
+        let added_flow = get_flow_from_valves(valve_flow_data, &state.valves_open);
+        let new_score = state.score + added_flow;
+        if new_score >= max_score {
+            max_score = new_score;
+            trace!("Max score {} at step {}", max_score, state.step + 1);
+        } else {
+            // Optimism: If we opened all valves now and it still wouldnt be enough to hit max score,
+            // we can safely ignore expanding this node further.
+            let optimistic_score = state.score + max_valve_flow * ((max_steps - state.step) as u32);
+            if optimistic_score <= max_score {
+                continue;
+            }
+        }
+
+        // If all valves are open
+        let all_valves_open = state.valves_open.len() == num_useful_valves;
+        if all_valves_open {
+            state_stack.push(
+                Node {
+                    step: state.step + 1,
+                    prev_location: state.prev_location.clone(),
+                    location: state.location.clone(),
+                    prev_elephant_location: state.prev_elephant_location.clone(),
+                    elephant_location: state.elephant_location.clone(),
+                    valves_open: state.valves_open.clone(),
+                    score: new_score,
+                }
+            );
+            continue;
+        }
+
+        // Add transitions, pruning cyclic ones by using the previous location
+        let transition_str = valve_transition_mod.get(&state.location).unwrap();
+
+        for transition in transition_str.split(", ") {                    
+            let mut new_valves_open = state.valves_open.clone();
+
+            let mut dest_self = transition.to_string();
+            let open_self = dest_self == "OPEN";
+            if open_self {
+                dest_self = state.location.clone();
+                let flow_zero = *valve_flow_data.get(&state.location).unwrap() == 0;
+                let cur_valve_open = state.valves_open.contains(&state.location);
+                if !cur_valve_open && !flow_zero {
+                    new_valves_open.insert(state.location.clone());
+                } else {
+                    continue;
+                }
+            }
+
+            let is_cyclic = (dest_self == state.prev_location) && !open_self;
+            if is_cyclic {
+                continue;
+            }
+
+            // If not using elephant, add node right away
+            if !use_elephant {
+                state_stack.push(
+                    Node {
+                        step: state.step + 1,
+                        prev_location: state.location.clone(),
+                        location: dest_self.clone(),
+                        prev_elephant_location: state.prev_elephant_location.clone(),
+                        elephant_location: state.elephant_location.clone(),
+                        valves_open: new_valves_open.clone(),
+                        score: new_score,
+                    }
+                );
+                continue;
+            }
+
+            // If using elephant, go through its possible transitions
+            let transition_str_ele = valve_transition_mod.get(&state.elephant_location).unwrap();
+            for transition_ele in transition_str_ele.split(", ") {  
+                let mut ele_valves_open = new_valves_open.clone();                  
+                let mut dest_ele = transition_ele.to_string();
+                let open_ele = dest_ele == "OPEN";
+                if open_ele {
+                    dest_ele = state.elephant_location.clone();
+                    let flow_zero = *valve_flow_data.get(&state.elephant_location).unwrap() == 0;
+                    let cur_valve_open = ele_valves_open.contains(&state.elephant_location);
+                    if !cur_valve_open && !flow_zero {
+                        ele_valves_open.insert(state.elephant_location.clone());
+                    } else {
+                        continue;
+                    }
+                }
+
+                let is_cyclic_ele = (dest_ele == state.prev_elephant_location) && !open_ele;
+                if is_cyclic_ele {
+                    continue;
+                }
+
+                // Add node if we passed all tests
+                state_stack.push(
+                    Node {
+                        step: state.step + 1,
+                        prev_location: state.location.clone(),
+                        location: dest_self.clone(),
+                        prev_elephant_location: state.elephant_location.clone(),
+                        elephant_location: dest_ele.clone(),
+                        valves_open: ele_valves_open.clone(),
+                        score: new_score,
+                    }
+                );
+            }
+        }
+    }
+
+    max_score
+}
+
+pub fn parse_valves(data: &str) -> Result<ValveData, ParseError> {
+    let expr = r"^Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z][A-Z](, [A-Z][A-Z])*)$";
+    let re = Regex::new(expr).unwrap();
+
+    let mut valve_flow_data = HashMap::new();
+    let mut valve_transition_data = HashMap::new();
+    for (i, line) in data.lines().enumerate() {
+        let cap = error::expect(re.captures(line), i + 1, 1,
+            "a line like \"Valve AA has flow rate=0; tunnels lead to valves DD, II\"")?;
+        let valve_name = cap[1].to_string();
+        let valve_flow = error::parse_token(i + 1, line, cap.get(2).unwrap().as_str(), "a flow rate")?;
+        let valve_transitions = cap[3].to_string();
+
+        valve_flow_data.insert(valve_name.clone(), valve_flow);
+        valve_transition_data.insert(valve_name.clone(), valve_transitions);
+    }
+
+    // All the tunnels must lead to known valves, and we start at AA
+    for (i, line) in data.lines().enumerate() {
+        let cap = re.captures(line).unwrap();
+        let tunnels = cap.get(3).unwrap().as_str();
+        for valve in tunnels.split(", ") {
+            if !valve_flow_data.contains_key(valve) {
+                return Err(ParseError::new(i + 1, error::column_of(line, valve), "a tunnel to a known valve"));
+            }
+        }
+    }
+    if !valve_flow_data.contains_key("AA") {
+        return Err(ParseError::new(data.lines().count() + 1, 1, "a starting valve AA"));
+    }
+
+    Ok(ValveData { flow: valve_flow_data, transitions: valve_transition_data })
+}
+
+impl Solution for Day16 {
+    type Input = ValveData;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_valves(data)
+    }
+
+    // Part 1: Open valves alone for 30 minutes
+    fn part1(valves: &Self::Input) -> Answer {
+        let max_steps: usize = 30;
+        Answer::from(search_puzzle(&valves.flow, &valves.transitions, max_steps, false))
+    }
+
+    // Part 2: Open valves with an elephant for 26 minutes
+    fn part2(valves: &Self::Input) -> Answer {
+        let max_steps: usize = 26;
+        Answer::from(search_puzzle(&valves.flow, &valves.transitions, max_steps, true))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day16>(filename, part)
+}
@@ -0,0 +1,280 @@
+// Solution to Day 17 puzzle
+// https://adventofcode.com/2022/day/17
+
+use std::cmp::{max, min};
+use std::fmt;
+
+use ndarray::s;
+
+use animate;
+use cycle::CycleDetector;
+use error::{self, ParseError};
+use grid::Grid2D;
+use image::{self, Rgb};
+use solution::{self, Answer, Solution};
+
+pub struct Day17;
+
+
+const GRID_WIDTH: usize = 7;
+const GRID_HEIGHT: usize = 100000;
+
+// Each rock is at most 4 tall, and starts 3 above the tower, so the chamber holds
+// at least this many. Any more than that and the tower height is extrapolated
+// from a cycle instead.
+const MAX_SIMULATED_ROCKS: i64 = (GRID_HEIGHT as i64 - 7) / 4;
+
+// A cell in the chamber is empty, or filled by a rock of the given type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Rock(usize),
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::Rock(_) => write!(f, "#"),
+        }
+    }
+}
+
+impl Cell {
+    // Each type of rock has its own colour
+    pub fn colour(&self) -> Rgb {
+        const ROCK_COLOURS: [Rgb; 5] = [
+            [230, 80, 60], [240, 190, 50], [80, 180, 90], [70, 140, 220], [170, 100, 200],
+        ];
+        match self {
+            Cell::Empty => [15, 15, 25],
+            Cell::Rock(idx) => ROCK_COLOURS[idx % ROCK_COLOURS.len()],
+        }
+    }
+}
+
+pub type Chamber = Grid2D<Cell>;
+
+pub type RockPoints = Vec<[i64; 2]>;
+
+// What decides how the next rocks fall: the next rock, the next gust,
+// and how far below the top of the tower each column is filled
+pub type State = (usize, usize, [i64; GRID_WIDTH]);
+
+pub fn init_rock_pts() -> Vec<RockPoints> {
+    vec![
+        // Rock 1:
+        // ####
+        vec![[0,0], [0,1], [0,2], [0,3]],
+        // Rock 2:
+        // .#.
+        // ###
+        // .#.
+        vec![[0,1], [-1,0], [-1,1], [-1,2], [-2,1]],
+        // Rock 3:
+        // ..#
+        // ..#
+        // ###
+        vec![[0,0], [0,1], [0,2], [-1,2], [-2,2]],
+        // Rock 4:
+        // #
+        // #
+        // #
+        // #
+        vec![[0,0], [-1,0], [-2,0], [-3,0]],
+        // Rock 5:
+        // ##
+        // ##
+        vec![[0,0], [0,1], [-1,0], [-1,1]],
+    ]
+}
+
+pub fn get_rock_pts(init_pts: &RockPoints, origin: [i64; 2]) -> RockPoints {
+    let mut rock_pts = Vec::new();
+
+    let x = origin[0];
+    let y = origin[1];
+    for elem in init_pts.iter() {
+        rock_pts.push([elem[0] + x, elem[1] + y]);
+    }
+    rock_pts
+}
+
+// Checks if any of the points hit the walls, the floor, or a rock
+pub fn check_contact(grid: &Chamber, pts: &RockPoints) -> bool {
+    pts.iter().any(|pt| grid.get(pt[0], pt[1]) != Some(&Cell::Empty))
+}
+
+pub fn get_gust_dirs(gusts: &str) -> Vec<i64> {
+    let mut gust_vec = Vec::new();
+    for ch in gusts.chars() {
+        gust_vec.push(
+            match ch {
+                '>' => 1,
+                '<' => -1,
+                _ => {
+                    warn!("Invalid gust {}", ch);
+                    0
+                },
+            }
+        );
+    }
+    gust_vec
+}
+
+pub fn rock_sim(grid: &mut Chamber, gusts: &str, num_rocks: i64) -> i64 {
+
+    // Initialize
+    // let mut new_grid;
+    let rock_pts = init_rock_pts();
+    let num_rock_types = rock_pts.len() as i64;
+    let gust_vec = get_gust_dirs(gusts);
+    let num_gusts = gust_vec.len() as i64;
+    let mut step: i64 = 0;
+    let mut tower_height: i64 = 0;
+
+    let mut column_heights = [0; GRID_WIDTH];
+    let mut cycles = CycleDetector::new();
+
+    // Loop through all the steps
+    for r in 0..num_rocks {
+        let row: i64 = (GRID_HEIGHT as i64) - 1 - tower_height - 3;
+        let col = 2;
+        let rock_idx = (r % num_rock_types) as usize;
+        let mut rock_stopped = false;
+
+        // Simulate a single rock falling to completion
+        let mut pts = get_rock_pts(&rock_pts[rock_idx], [row, col]);
+        while !rock_stopped {
+            let mut new_pts = pts.clone();
+
+            // Try move according to gust.
+            // If in collision, nothing happens
+            // let dir = get_gust_dir(&gusts[..], step);
+            let dir = gust_vec[(step % num_gusts) as usize];
+            for pt in new_pts.iter_mut() {
+                if dir == 1 {
+                    pt[1] += 1;
+                } else {
+                    pt[1] -= 1;
+                }
+            }
+            if check_contact(grid, &new_pts) {
+                trace!("No gust");
+            } else {
+                trace!("Gust pushes rock in {}", dir);
+                pts = new_pts;
+            }
+
+            // Try move down.
+            // If in collision, the rock stops
+            let mut new_pts = pts.clone();
+            for pt in new_pts.iter_mut() {
+                pt[0] += 1;
+            }
+            if !check_contact(grid, &new_pts) {
+                pts = new_pts;
+                trace!("Collision free, stepping");
+            } else {
+                // Update the tower height if stopped
+                trace!("Rock stopped at {:?}", pts);
+                let mut delta_height = 0;
+                for pt in pts.iter() {
+                    grid[(pt[0] as usize, pt[1] as usize)] = Cell::Rock(rock_idx);
+                    let pt_height = GRID_HEIGHT as i64 - pt[0];
+                    delta_height = max(delta_height, pt_height - tower_height);
+                    column_heights[pt[1] as usize] = max(column_heights[pt[1] as usize], pt_height);
+                }
+                tower_height += delta_height;
+                trace!("New tower height: {}", tower_height);
+                rock_stopped = true;
+            }
+
+            let falling: &[[i64; 2]] = if rock_stopped { &[] } else { &pts };
+            animate::frame(|| render_chamber(grid, tower_height, falling));
+
+            // Update step number
+            step += 1;
+        }
+
+        // Once the state repeats, the tower grows by the same height every cycle
+        if num_rocks <= MAX_SIMULATED_ROCKS {
+            continue;
+        }
+        let mut skyline = [0; GRID_WIDTH];
+        for (depth, height) in skyline.iter_mut().zip(column_heights.iter()) {
+            *depth = tower_height - height;
+        }
+        let state: State = ((rock_idx + 1) % rock_pts.len(), (step % num_gusts) as usize, skyline);
+        if cycles.push(state, tower_height).is_some() {
+            let total_height = cycles.extrapolate(num_rocks as usize - 1).unwrap();
+            dump_tower(grid, tower_height, r + 1);
+            animate::last_frame(|| format!("Cycle found, so the tower will reach {}\n{}",
+                total_height, render_chamber(grid, tower_height, &[])));
+            return total_height;
+        }
+    }
+
+    animate::last_frame(|| render_chamber(grid, tower_height, &[]));
+    dump_tower(grid, tower_height, num_rocks);
+    tower_height
+}
+
+// Writes an image of the tower as far as it was simulated, which stops short
+// of the number of rocks asked for when a cycle is found
+fn dump_tower(grid: &Chamber, tower_height: i64, rocks_simulated: i64) {
+    if image::is_dumping() {
+        let tower = grid.crop(GRID_HEIGHT - tower_height as usize..GRID_HEIGHT, 0..GRID_WIDTH);
+        image::dump_ppm(&format!("day17_tower_{}_rocks", rocks_simulated), &tower.map(Cell::colour), 4);
+    }
+}
+
+// Draws the top of the tower, with the falling rock shown as @
+pub fn render_chamber(grid: &Chamber, tower_height: i64, falling: &[[i64; 2]]) -> String {
+    // Leave room above the tower for a new rock to appear
+    let top = max(GRID_HEIGHT as i64 - tower_height - 7, 0) as usize;
+    let bottom = min(top + animate::MAX_ROWS - 1, GRID_HEIGHT);
+    let mut view = Grid2D { cells: grid.cells.slice(s![top..bottom, ..]).map(|cell| match cell {
+        Cell::Empty => '.',
+        Cell::Rock(_) => '#',
+    })};
+    for pt in falling {
+        if let Some(c) = view.get_mut(pt[0] - top as i64, pt[1]) {
+            *c = '@';
+        }
+    }
+    format!("Tower height: {}\n{}", tower_height, view)
+}
+
+impl Solution for Day17 {
+    type Input = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let gusts = data.trim();
+        match gusts.chars().position(|c| c != '<' && c != '>') {
+            Some(col) => Err(ParseError::new(1, col + 1, "a gust of < or >")),
+            None if gusts.is_empty() => Err(ParseError::new(1, 1, "at least one gust of < or >")),
+            None => Ok(gusts.to_string()),
+        }
+    }
+
+    // Part 1
+    fn part1(gusts: &Self::Input) -> Answer {
+        let num_steps: i64 = 2022;
+        let mut grid = Chamber::new(GRID_HEIGHT, GRID_WIDTH, Cell::Empty);
+        let tower_height = rock_sim(&mut grid, gusts, num_steps);
+        trace!("\n{}", render_chamber(&grid, tower_height, &[]));
+        Answer::from(tower_height)
+    }
+
+    // Part 2
+    fn part2(gusts: &Self::Input) -> Answer {
+        let num_steps: i64 = 1000000000000;
+        let mut grid = Chamber::new(GRID_HEIGHT, GRID_WIDTH, Cell::Empty);
+        Answer::from(rock_sim(&mut grid, gusts, num_steps))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day17>(filename, part)
+}
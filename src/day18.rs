@@ -0,0 +1,104 @@
+// Solution to Day 18 puzzle
+// https://adventofcode.com/2022/day/18
+
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use error::{self, ParseError};
+use search;
+use solution::{self, Answer, Solution};
+
+pub struct Day18;
+
+const GRID_SIZE: usize = 20;  // From visually inspecting data
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32
+}
+
+// Returns all adjacent points to a cube
+pub fn get_adjacent_points(pt: &Point) -> Vec<Point> {
+    vec![
+        Point{x: pt.x + 1, y: pt.y, z: pt.z},
+        Point{x: pt.x - 1, y: pt.y, z: pt.z},
+        Point{x: pt.x, y: pt.y + 1, z: pt.z},
+        Point{x: pt.x, y: pt.y - 1, z: pt.z},
+        Point{x: pt.x, y: pt.y, z: pt.z + 1},
+        Point{x: pt.x, y: pt.y, z: pt.z - 1},
+    ]  
+}
+
+// Expansion algorithm for Part 2
+// A face is exposed if the air next to it can reach the edge of the grid
+pub fn is_face_exposed(lava_cubes_hash: &HashSet<Point>, pt: &Point) -> bool {
+    if lava_cubes_hash.contains(pt) {
+        return false;
+    }
+    let grid_size = GRID_SIZE as i32;
+    let is_outside = |cur_pt: &Point| {
+        cur_pt.x <= 0 || cur_pt.x >= grid_size ||
+        cur_pt.y <= 0 || cur_pt.y >= grid_size ||
+        cur_pt.z <= 0 || cur_pt.z >= grid_size
+    };
+    let neighbours = |cur_pt: &Point| {
+        get_adjacent_points(cur_pt).into_iter().filter(|p| !lava_cubes_hash.contains(p))
+    };
+
+    // If the search runs out of air before reaching the edge, it's trapped air!
+    search::bfs(*pt, neighbours, is_outside).is_some()
+}
+
+
+pub fn calc_surface_area(lava_cubes: &HashSet<Point>,
+                     consider_trapped_air: bool) -> u32 {
+    let mut total_surface_area = 0;
+    for cube in lava_cubes {
+        // Check all 6 cube faces
+        for pt in get_adjacent_points(cube).iter() {
+            let add_face = match consider_trapped_air {
+                true => is_face_exposed(lava_cubes, pt),
+                false => !lava_cubes.contains(pt),
+            };
+            if add_face {
+                total_surface_area += 1;
+            }
+        }
+    }
+    total_surface_area
+}
+
+impl Solution for Day18 {
+    type Input = HashSet<Point>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let mut lava_cubes = HashSet::new();
+        for (i, line) in data.lines().enumerate() {
+            let mut coords = Vec::new();
+            for token in line.split(',') {
+                coords.push(error::parse_token::<i32>(i + 1, line, token, "a coordinate")?);
+            }
+            let (x, y, z) = error::expect(coords.into_iter().collect_tuple(), i + 1, 1,
+                "a cube of the form x,y,z")?;
+            lava_cubes.insert(Point {x, y, z});
+        }
+        Ok(lava_cubes)
+    }
+
+    // Part 1
+    fn part1(lava_cubes: &Self::Input) -> Answer {
+        Answer::from(calc_surface_area(lava_cubes, false))
+    }
+
+    // Part 2
+    fn part2(lava_cubes: &Self::Input) -> Answer {
+        Answer::from(calc_surface_area(lava_cubes, true))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day18>(filename, part)
+}
@@ -0,0 +1,227 @@
+// Solution to Day 19 puzzle
+// https://adventofcode.com/2022/day/19
+
+use std::cmp::{min, max};
+
+use rayon::prelude::*;
+use regex::Regex;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day19;
+
+
+// Structs to describe the puzzle
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Blueprint {
+    pub id: u32,
+    pub ore_robot_ore_cost: u32,
+    pub clay_robot_ore_cost: u32,
+    pub obsidian_robot_ore_cost: u32,
+    pub obsidian_robot_clay_cost: u32,
+    pub geode_robot_ore_cost: u32,
+    pub geode_robot_obsidian_cost: u32
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct State {
+    pub step: u32,
+    pub ore: u32,
+    pub clay: u32,
+    pub obsidian: u32,
+    pub geode: u32,
+    pub n_ore_robots: u32,
+    pub n_clay_robots: u32,
+    pub n_obsidian_robots: u32,
+    pub n_geode_robots: u32,
+    pub ore_robot_building: u32,
+    pub clay_robot_building: u32,
+    pub obsidian_robot_building: u32,
+    pub geode_robot_building: u32,
+}
+
+
+// Input file parsing helper
+pub fn parse_blueprints(data: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let expr = "Blueprint ([0-9]+): \
+                Each ore robot costs ([0-9]+) ore. \
+                Each clay robot costs ([0-9]+) ore. \
+                Each obsidian robot costs ([0-9]+) ore and ([0-9]+) clay. \
+                Each geode robot costs ([0-9]+) ore and ([0-9]+) obsidian.";
+    let re = Regex::new(expr).unwrap();
+
+    let mut blueprints = Vec::<Blueprint>::new();
+    for (i, line) in data.lines().enumerate() {
+        let cap = error::expect(re.captures(line), i + 1, 1, "a blueprint with the costs of all 4 robots")?;
+        let val = |idx: usize| {
+            error::parse_token::<u32>(i + 1, line, cap.get(idx).unwrap().as_str(), "a number")
+        };
+        blueprints.push(
+            Blueprint {
+                id: val(1)?,
+                ore_robot_ore_cost: val(2)?,
+                clay_robot_ore_cost: val(3)?,
+                obsidian_robot_ore_cost: val(4)?,
+                obsidian_robot_clay_cost: val(5)?,
+                geode_robot_ore_cost: val(6)?,
+                geode_robot_obsidian_cost: val(7)?
+            }
+        );
+    }
+    Ok(blueprints)
+}
+
+// Main simulation function
+pub fn get_max_geodes(blueprint: &Blueprint, n_steps: u32) -> u32 {
+    // Initialize
+    let init_state = State {
+        step: 0,
+        ore: 0, clay: 0, obsidian: 0, geode: 0,
+        n_ore_robots: 1, n_clay_robots: 0, n_obsidian_robots: 0, n_geode_robots: 0,
+        ore_robot_building: 0, clay_robot_building: 0, obsidian_robot_building: 0, geode_robot_building: 0
+    };
+    let mut state_stack = vec![init_state];
+
+    let mut max_geodes = 0;
+    let mut max_geodes_per_step = vec![0; (n_steps + 1) as usize];
+
+    let max_necessary_ore_production = max(
+        blueprint.ore_robot_ore_cost, 
+        max(
+            blueprint.clay_robot_ore_cost,
+            max (blueprint.obsidian_robot_ore_cost,
+                 blueprint.geode_robot_ore_cost)
+        )
+    );
+
+    // Simulate
+    while let Some(mut cur_state) = state_stack.pop() {
+        // If this is max step, don't add more to the stack
+        if cur_state.step >= n_steps {
+            continue;
+        }
+        // If we know we can have more geodes at this step from another path,
+        // skip expanding this node any further.
+        // This fudge factor of 2 works, but I'm not sure why this did and 1 didn't...
+        if max_geodes_per_step[cur_state.step as usize] > cur_state.geode + 2 {
+            continue;
+        }
+
+        // Simulate forward
+        cur_state.step += 1;
+        // Resources
+        cur_state.ore += cur_state.n_ore_robots;
+        cur_state.clay += cur_state.n_clay_robots;
+        cur_state.obsidian += cur_state.n_obsidian_robots;
+        cur_state.geode += cur_state.n_geode_robots;
+        // Building
+        if cur_state.ore_robot_building > 0 {
+            cur_state.ore_robot_building = 0;
+            cur_state.n_ore_robots += 1;
+        }
+        if cur_state.clay_robot_building > 0 {
+            cur_state.clay_robot_building = 0;
+            cur_state.n_clay_robots += 1;
+        }
+        if cur_state.obsidian_robot_building > 0 {
+            cur_state.obsidian_robot_building = 0;
+            cur_state.n_obsidian_robots += 1;
+        }
+        if cur_state.geode_robot_building > 0 {
+            cur_state.geode_robot_building = 0;
+            cur_state.n_geode_robots += 1;
+        }
+
+        // Check max geodes
+        if cur_state.geode > max_geodes {
+            max_geodes = cur_state.geode;
+            trace!("  [Blueprint {}] Max geodes so far: {}", blueprint.id, max_geodes);
+        }
+        if cur_state.geode > max_geodes_per_step[cur_state.step as usize] {
+            max_geodes_per_step[cur_state.step as usize] = cur_state.geode;
+        }
+
+        // Add possible transitions
+        // Geode robot
+        if cur_state.ore >= blueprint.geode_robot_ore_cost &&
+           cur_state.obsidian >= blueprint.geode_robot_obsidian_cost {
+            let mut new_state = cur_state;
+            new_state.ore -= blueprint.geode_robot_ore_cost;
+            new_state.obsidian -= blueprint.geode_robot_obsidian_cost;
+            new_state.geode_robot_building += 1;
+            state_stack.push(new_state);
+            continue;
+        }
+        // Obsidian robot
+        if cur_state.n_obsidian_robots < blueprint.geode_robot_obsidian_cost &&
+           cur_state.ore >= blueprint.obsidian_robot_ore_cost &&
+           cur_state.clay >= blueprint.obsidian_robot_clay_cost {
+            let mut new_state = cur_state;
+            new_state.ore -= blueprint.obsidian_robot_ore_cost;
+            new_state.clay -= blueprint.obsidian_robot_clay_cost;
+            new_state.obsidian_robot_building += 1;
+            state_stack.push(new_state);
+        }
+        // Clay robot
+        if cur_state.n_clay_robots < blueprint.obsidian_robot_clay_cost && 
+           cur_state.ore >= blueprint.clay_robot_ore_cost {
+            let mut new_state = cur_state;
+            new_state.ore -= blueprint.clay_robot_ore_cost;
+            new_state.clay_robot_building += 1;
+            state_stack.push(new_state);
+        }
+        // Ore robot
+        if cur_state.n_ore_robots < max_necessary_ore_production &&
+           cur_state.ore >= blueprint.ore_robot_ore_cost {
+            let mut new_state = cur_state;
+            new_state.ore -= blueprint.ore_robot_ore_cost;
+            new_state.ore_robot_building = 1;
+            state_stack.push(new_state);
+        }
+        // Do nothing
+        state_stack.push(cur_state);
+    }
+
+    info!("[Blueprint {}] Produced {} geodes\n", blueprint.id, max_geodes);
+    max_geodes
+}
+
+
+// Part 1 entry point
+pub fn get_quality_level(blueprints: &[Blueprint], n_steps: u32) -> u32 {
+    blueprints.par_iter()
+              .map(|b| b.id * get_max_geodes(b, n_steps))
+              .sum()
+}
+
+// Part 2 entry point
+pub fn get_geode_product(blueprints: &[Blueprint], n_steps: u32, n_blueprints: usize) -> u32 {
+    let n = min(n_blueprints, blueprints.len());
+    blueprints[0..n].par_iter()
+                    .map(|b| get_max_geodes(b, n_steps))
+                    .product()
+}
+
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_blueprints(data)
+    }
+
+    // Part 1
+    fn part1(blueprints: &Self::Input) -> Answer {
+        Answer::from(get_quality_level(blueprints, 24))
+    }
+
+    // Part 2
+    fn part2(blueprints: &Self::Input) -> Answer {
+        Answer::from(get_geode_product(blueprints, 32, 3))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day19>(filename, part)
+}
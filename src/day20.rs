@@ -0,0 +1,94 @@
+// Solution to Day 20 puzzle
+// https://adventofcode.com/2022/day/20
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day20;
+
+pub fn mix(mut data: Vec<i64>, num_mixes: usize) -> Vec<i64> {
+    let num_vals = data.len() as i64;
+    let mut orig_indices: Vec<i64> = (0..num_vals).collect();
+
+    for _ in 0..num_mixes {
+        let mut vals_moved = 0;
+        while vals_moved < num_vals {
+            // Calculate the target index
+            let cur_idx = orig_indices.iter().position(|&x| x == vals_moved)
+                                      .unwrap() as i64;
+            let mut target_idx = (cur_idx + data[cur_idx as usize]) % (num_vals - 1);
+            if target_idx < 0 {
+                target_idx += num_vals - 1;
+            }
+            trace!("Moving {} from index {} to {}", data[cur_idx as usize], cur_idx, target_idx);
+
+            // Swap elements along the motion direction
+            let dir = (target_idx - cur_idx).signum();
+            let num_moves = (target_idx - cur_idx).abs();
+            let mut orig = cur_idx;
+            for _ in 0..num_moves {
+                let new = orig + dir;
+                data.swap(orig as usize, new as usize);
+                orig_indices.swap(orig as usize, new as usize);
+                orig = new;
+            }
+
+            vals_moved += 1;
+            trace!("Step {}:\n{:?}", vals_moved, data);
+        }
+    }
+    data
+}
+
+pub fn get_coordinates(data: &[i64]) -> i64 {
+    let zero_idx = data.iter().position(|&x| x == 0).unwrap();
+    let n = data.len();
+    data[(zero_idx + 1000) % n] +
+    data[(zero_idx + 2000) % n] +
+    data[(zero_idx + 3000) % n]
+}
+
+
+pub fn decrypt_type_1(data: Vec<i64>) -> i64 {
+    // Mix the data once
+    let data = mix(data, 1);
+
+    // Compute the coordinates
+    get_coordinates(&data)
+}
+
+pub fn decrypt_type_2(data: Vec<i64>) -> i64 {
+    // Apply decryption key
+    let data: Vec<i64> = data.iter().map(|x| x * 811589153).collect();
+
+    // Mix the data a few times
+    let data = mix(data, 10);
+    
+    // Compute the coordinates
+    get_coordinates(&data)
+}
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        data.lines()
+            .enumerate()
+            .map(|(i, x)| error::parse_token(i + 1, x, x, "a number"))
+            .collect()
+    }
+
+    // Part 1
+    fn part1(data: &Self::Input) -> Answer {
+        Answer::from(decrypt_type_1(data.clone()))
+    }
+
+    // Part 2
+    fn part2(data: &Self::Input) -> Answer {
+        Answer::from(decrypt_type_2(data.clone()))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day20>(filename, part)
+}
@@ -0,0 +1,170 @@
+// Solution to Day 21 puzzle
+// https://adventofcode.com/2022/day/21
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day21;
+
+pub type MonkeyData = HashMap<String, String>;
+
+pub fn parse_data(raw_text: &str) -> Result<MonkeyData, ParseError> {
+    let mut data = HashMap::new();
+    for (i, line) in raw_text.lines().enumerate() {
+        let (key, val) = error::expect(line.split(": ").next_tuple(), i + 1, 1,
+            "a line of the form \"name: job\"")?;
+
+        // The job is either a number or an operation on two other monkeys
+        if val.parse::<i64>().is_err() {
+            let expr = val.split_whitespace().collect::<Vec<&str>>();
+            if expr.len() != 3 || !["+", "-", "*", "/"].contains(&expr[1]) {
+                return Err(ParseError::new(i + 1, error::column_of(line, val),
+                    "a number or an operation like \"aaaa + bbbb\""));
+            }
+        }
+        data.insert(key.to_string(), val.to_string());
+    }
+
+    // All the monkeys in the operations must exist, including root and humn
+    for (i, line) in raw_text.lines().enumerate() {
+        let val = line.split(": ").nth(1).unwrap();
+        for name in val.split_whitespace().step_by(2) {
+            if val.parse::<i64>().is_err() && !data.contains_key(name) {
+                return Err(ParseError::new(i + 1, error::column_of(line, name), "the name of a known monkey"));
+            }
+        }
+    }
+    for name in ["root", "humn"].iter() {
+        if !data.contains_key(*name) {
+            return Err(ParseError::new(raw_text.lines().count() + 1, 1, &format!("a monkey named {}", name)));
+        }
+    }
+    Ok(data)
+}
+
+pub fn eval_expression(data: &MonkeyData, monkey_name: &str) -> i64 {
+    let expr_str = &data[monkey_name];
+
+    if let Ok(num) = expr_str.parse::<i64>() {
+        // Base Case: If the expression parses to a number, return the number
+        num
+    } else {
+        // Recursive Case: Split the string and evaluate each sub-portion
+        let (left, oper, right) = expr_str.split_whitespace().next_tuple().unwrap();
+        match oper {
+            "+" => eval_expression(data, left) + eval_expression(data, right),
+            "-" => eval_expression(data, left) - eval_expression(data, right),
+            "*" => eval_expression(data, left) * eval_expression(data, right),
+            "/" => eval_expression(data, left) / eval_expression(data, right),
+            _ => {
+                warn!("Invalid operation {}, returning zero", oper);
+                0
+            }
+        }
+    }
+}
+
+
+pub fn find_human_answer(data: &MonkeyData) -> i64 {
+    // First, parse the "root" expression.
+    let root_str = &data[&String::from("root")];
+    let (root_left, _, root_right) = root_str.split_whitespace().next_tuple().unwrap();
+
+    // Figure out whether the left or right side contains "humn" by evaluating twice.
+    let left_control = eval_expression(data, root_left);
+    let right_control = eval_expression(data, root_right);
+
+    let mut new_data = data.clone();
+    let humn_val = data[&String::from("humn")].parse::<i64>().unwrap() * 100;  // Some random mutation
+    *new_data.get_mut(&String::from("humn")).unwrap() = humn_val.to_string();
+    let left_test = eval_expression(&new_data, root_left);
+    let right_test = eval_expression(&new_data, root_right);
+    if left_test != left_control {
+        info!("humn is on the left side of root.");
+        return tweak_human_answer(data, &data[root_left], right_test);
+    } else if right_test != right_control {
+        info!("humn is on the right side of root.");
+        return tweak_human_answer(data, &data[root_right], left_test);
+    }
+    
+    warn!("Neither left nor right side modified, returning zero");
+    0
+}
+
+pub fn tweak_human_answer(data: &MonkeyData, expr: &str, answer: i64) -> i64 {
+    trace!("Tweaking {} to be equal to {}", expr, answer);
+    let (left, oper, right) = expr.split_whitespace().next_tuple().unwrap();
+    let left_control = eval_expression(data, left);
+    let right_control = eval_expression(data, right);
+
+    // Recursive case: Figure out which side of the tree contains humn
+    let mut new_data = data.clone();
+    let humn_val = data[&String::from("humn")].parse::<i64>().unwrap() * 100;  // Some random mutation
+    *new_data.get_mut(&String::from("humn")).unwrap() = humn_val.to_string();
+    let left_test = eval_expression(&new_data, left);
+    let right_test = eval_expression(&new_data, right);
+    if left_test != left_control {
+        trace!("humn is on the left side");
+        let new_answer = match oper {
+            "+" => answer - right_test,
+            "-" => answer + right_test,
+            "*" => answer / right_test,
+            "/" => answer * right_test,
+            _ => {
+                warn!("Invalid operation {}, returning zero", oper);
+                0
+            }
+        };
+        if left == "humn" {
+            trace!("humn should be equal to {}", new_answer);
+            return new_answer;
+        }
+        return tweak_human_answer(data, &data[left], new_answer);
+    } else if right_test != right_control {
+        trace!("humn is on the right side");
+        let new_answer = match oper {
+            "+" => answer - left_test,
+            "-" => left_test - answer,
+            "*" => answer / left_test,
+            "/" => left_test / answer,
+            _ => {
+                warn!("Invalid operation {}, returning zero", oper);
+                0
+            }
+        };
+        if right == "humn" {
+            trace!("humn should be equal to {}", new_answer);
+            return new_answer;
+        }
+        return tweak_human_answer(data, &data[right], new_answer);
+    }
+
+    warn!("Neither left nor right side modified, returning zero");
+    0
+}
+
+impl Solution for Day21 {
+    type Input = MonkeyData;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_data(data)
+    }
+
+    // Part 1
+    fn part1(data: &Self::Input) -> Answer {
+        Answer::from(eval_expression(data, "root"))
+    }
+
+    // Part 2
+    fn part2(data: &Self::Input) -> Answer {
+        Answer::from(find_human_answer(data))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day21>(filename, part)
+}
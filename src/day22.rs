@@ -0,0 +1,316 @@
+// Solution to Day 22 puzzle
+// https://adventofcode.com/2022/day/22
+
+use std::fmt;
+
+use itertools::Itertools;
+
+use error::{self, ParseError};
+use geometry::{Direction, Point2, Turn};
+use grid::Grid2D;
+use image::{self, Rgb};
+use solution::{self, Answer, Solution};
+
+pub struct Day22;
+
+// Where we are on the map, and which way we face
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct State {
+    pub pos: Point2,
+    pub facing: Direction,
+}
+
+// A step of the path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Forward(u32),
+    Turn(Turn),
+}
+
+// The map is only defined where it has tiles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Tile::Void => ' ',
+            Tile::Open => '.',
+            Tile::Wall => '#',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Tile {
+    pub fn colour(&self) -> Rgb {
+        match self {
+            Tile::Void => [0, 0, 0],
+            Tile::Open => [200, 200, 190],
+            Tile::Wall => [70, 70, 80],
+        }
+    }
+}
+
+pub type Map = Grid2D<Tile>;
+
+// Colour of the walked path, which gets lighter the further along it is
+fn trail_colour(step: usize, num_steps: usize) -> Rgb {
+    let progress = (step * 200 / num_steps.max(1)) as u8;
+    [220, 30 + progress, 30]
+}
+
+// Writes an image of the map with the path walked over it
+fn dump_trail(name: &str, map: &Map, trail: &[State]) {
+    if !image::is_dumping() {
+        return;
+    }
+    let mut view = map.map(Tile::colour);
+    for (i, state) in trail.iter().enumerate() {
+        view[(state.pos.y as usize, state.pos.x as usize)] = trail_colour(i, trail.len());
+    }
+    image::dump_ppm(name, &view, 3);
+}
+
+pub fn parse_data(raw_text: &str) -> Result<(Map, Vec<Step>, State), ParseError> {
+    // Split the file into the two parts
+    let (map_data, path_data) = error::expect(raw_text.split("\n\n").next_tuple(),
+        raw_text.lines().count() + 1, 1, "a blank line between the map and the path")?;
+    let path_line = map_data.lines().count() + 2;
+    let path_data = path_data.trim_end();
+
+    // The first part becomes a map, which is undefined past the end of the shorter rows
+    let map = Map::parse_padded(map_data, Tile::Void, "a space, . or #", |c| match c {
+        ' ' => Some(Tile::Void),
+        '.' => Some(Tile::Open),
+        '#' => Some(Tile::Wall),
+        _ => None,
+    })?;
+    // Also, find the initial position
+    let init_col = error::expect((0..map.n_cols()).find(|&col| map[(0, col)] == Tile::Open), 1, 1,
+        "an open tile on the first row")?;
+    let init_pos = State { pos: Point2::from_row_col(0, init_col), facing: Direction::East };
+
+    // The second part becomes a path
+    // This was shamelessly ripped from this Stack Overflow question:
+    // https://stackoverflow.com/questions/32257273/split-a-string-keeping-the-separators
+    if let Some(col) = path_data.chars().position(|c| !(c.is_ascii_digit() || c == 'L' || c == 'R')) {
+        return Err(ParseError::new(path_line, col + 1, "a number of steps, L, or R"));
+    }
+    let mut tokens = Vec::new();
+    let mut last = 0;
+    for (index, matched) in path_data.match_indices(|c: char| !(c.is_numeric())) {
+        if last != index {
+            tokens.push(&path_data[last..index]);
+        }
+        tokens.push(matched);
+        last = index + matched.len();
+    }
+    if last < path_data.len() {
+        tokens.push(&path_data[last..]);
+    }
+    let mut path = Vec::new();
+    for token in tokens {
+        path.push(match Turn::from_lr(token) {
+            Some(turn) => Step::Turn(turn),
+            None => Step::Forward(error::parse_token(path_line, path_data, token, "a number of steps")?),
+        });
+    }
+
+    Ok((map, path, init_pos))
+}
+
+// Gets the tile at a position, which is void off the map
+fn tile_at(map: &Map, pos: Point2) -> Tile {
+    *map.get(pos.y, pos.x).unwrap_or(&Tile::Void)
+}
+
+// The password is made of the 1-indexed row and column, and the facing
+pub fn password(state: &State) -> u32 {
+    let facing_score = match state.facing {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    };
+    (state.pos.y as u32 + 1) * 1000 + (state.pos.x as u32 + 1) * 4 + facing_score
+}
+
+pub fn simulate(map: &Map, path: &[Step], init_pos: State) -> (State, u32) {
+    let mut state = init_pos;
+    // Only kept to draw the path
+    let mut trail = vec![state];
+    for step in path.iter() {
+        trace!("Executing step {:?}", step);
+
+        match *step {
+            // Keep moving
+            Step::Forward(n_steps) => {
+                for _ in 0..n_steps {
+                    let mut new_pos = state.pos.step(state.facing);
+                    // Wrap around to the other side of the map, by going back to its edge
+                    if tile_at(map, new_pos) == Tile::Void {
+                        let back = state.facing.turn(Turn::Around);
+                        new_pos = state.pos;
+                        while tile_at(map, new_pos.step(back)) != Tile::Void {
+                            new_pos = new_pos.step(back);
+                        }
+                    }
+
+                    // Handle the wall condition
+                    if tile_at(map, new_pos) == Tile::Wall {
+                        break;
+                    }
+                    state.pos = new_pos;
+                    if image::is_dumping() {
+                        trail.push(state);
+                    }
+                }
+            },
+            // Otherwise, turn in place
+            Step::Turn(turn) => state.facing = state.facing.turn(turn),
+        }
+
+        trace!("\tState is now {:?}", state);
+    }
+
+    dump_trail("day22_path", map, &trail);
+    (state, password(&state))
+}
+
+
+pub fn simulate_puzzle_cube(map: &Map, path: &[Step], init_pos: State) -> (State, u32) {
+    // Specific to the puzzle input layout
+    //        _____ _____
+    //       |     |     |
+    //       |  1  |  2  |
+    //       |_____|_____|
+    //       |     |
+    //       |  3  |
+    //  _____|_____|
+    // |     |     |
+    // |  4  |  5  |
+    // |_____|_____|
+    // |     |
+    // |  6  |
+    // |_____|
+         
+    use geometry::Direction::*;
+
+    let n_rows = map.n_rows() as i64;
+    let n_cols = map.n_cols() as i64;
+
+    let mut state = init_pos;
+    // Only kept to draw the path
+    let mut trail = vec![state];
+    for step in path.iter() {
+        trace!("Executing step {:?}", step);
+
+        match *step {
+            // Keep moving
+            Step::Forward(n_steps) => {
+                for _ in 0..n_steps {
+                    let (row, col) = (state.pos.y, state.pos.x);
+                    let next = state.pos.step(state.facing);
+                    let (next_row, next_col) = (next.y, next.x);
+                    let (new_row, new_col, new_facing) = match state.facing {
+                        // Wrap around on right
+                        // Face 2 right -> Face 5 right
+                        East if next_row < 50 && next_col >= n_cols => (149 - row, 99, West),
+                        // Face 3 right -> Face 2 down
+                        East if (50..100).contains(&next_row) && next_col >= 100 => (49, 100 + (row - 50), North),
+                        // Face 5 right -> Face 2 right
+                        East if (100..150).contains(&next_row) && next_col >= 100 => (49 - (row - 100), n_cols - 1, West),
+                        // Face 6 right -> Face 5 down
+                        East if (150..200).contains(&next_row) && next_col >= 50 => (149, 50 + (row - 150), North),
+
+                        // Wrap around on left
+                        // Face 1 left -> Face 4 left
+                        West if next_row < 50 && next_col < 50 => (149 - row, 0, East),
+                        // Face 3 left -> Face 4 up
+                        West if (50..100).contains(&next_row) && next_col < 50 => (100, row - 50, South),
+                        // Face 4 left -> Face 1 left
+                        West if (100..150).contains(&next_row) && next_col < 0 => (49 - (row - 100), 50, East),
+                        // Face 6 left -> Face 1 up
+                        West if (150..200).contains(&next_row) && next_col < 0 => (0, (row - 150) + 50, South),
+
+                        // Wrap around on top
+                        // Face 1 up -> Face 6 left
+                        North if next_row < 0 && (50..100).contains(&next_col) => (150 + (col - 50), 0, East),
+                        // Face 2 up -> Face 6 down
+                        North if next_row < 0 && (100..150).contains(&next_col) => (n_rows - 1, col - 100, North),
+                        // Face 4 up -> Face 3 left
+                        North if next_row < 100 && next_col < 50 => (50 + col, 50, East),
+
+                        // Wrap around to bottom
+                        // Face 2 down -> Face 3 right
+                        South if next_row >= 50 && (100..150).contains(&next_col) => (50 + (col - 100), 99, West),
+                        // Face 5 down -> Face 6 right
+                        South if next_row >= 150 && (50..100).contains(&next_col) => (150 + (col - 50), 49, West),
+                        // Face 6 down -> Face 2 up
+                        South if next_row >= 200 && next_col < 50 => (0, 100 + col, South),
+
+                        facing => (next_row, next_col, facing),
+                    };
+
+                    // Handle the wall condition
+                    let new_state = State { pos: Point2::new(new_col, new_row), facing: new_facing };
+                    if tile_at(map, new_state.pos) == Tile::Wall {
+                        break;
+                    }
+                    state = new_state;
+                    if image::is_dumping() {
+                        trail.push(state);
+                    }
+                }
+            },
+            // Otherwise, turn in place
+            Step::Turn(turn) => state.facing = state.facing.turn(turn),
+        }
+
+        trace!("\tState is now {:?}", state);
+    }
+
+    dump_trail("day22_cube_path", map, &trail);
+    (state, password(&state))
+}
+
+
+// The cube layout handled by simulate_puzzle_cube has faces of this size
+pub const PUZZLE_FACE_SIZE: usize = 50;
+
+impl Solution for Day22 {
+    type Input = (Map, Vec<Step>, State);
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        let (map, path, init_pos) = parse_data(data)?;
+        trace!("Map:\n{}\n\nInit Pos: {:?}\n", map, init_pos);
+        Ok((map, path, init_pos))
+    }
+
+    // Part 1
+    fn part1((map, path, init_pos): &Self::Input) -> Answer {
+        let (final_pos, password) = simulate(map, path, *init_pos);
+        info!("Final position = {:?}", final_pos);
+        Answer::from(password)
+    }
+
+    // Part 2 : Only works for puzzle input!
+    fn part2((map, path, init_pos): &Self::Input) -> Answer {
+        if map.n_rows() != 4 * PUZZLE_FACE_SIZE || map.n_cols() != 3 * PUZZLE_FACE_SIZE {
+            warn!("Cube folding is only implemented for the puzzle input layout");
+            return Answer::None;
+        }
+        let (final_cube_pos, cube_password) = simulate_puzzle_cube(map, path, *init_pos);
+        info!("Final cube position = {:?}", final_cube_pos);
+        Answer::from(cube_password)
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day22>(filename, part)
+}
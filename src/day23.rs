@@ -0,0 +1,168 @@
+// Solution to Day 23 puzzle
+// https://adventofcode.com/2022/day/23
+
+use animate;
+use error::{self, ParseError};
+use geometry::{Direction, Direction8, Point2};
+use grid::Grid2D;
+use solution::{self, Answer, Solution};
+
+pub struct Day23;
+
+// Handy type aliases
+pub type State = Vec<Point2>;
+
+// Get initial elf positions
+pub fn parse_data(data: &str) -> Result<State, ParseError> {
+    let grid = Grid2D::parse(data, "an elf # or empty ground .", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(grid.iter()
+        .filter(|&(_, &is_elf)| is_elf)
+        .map(|((row, col), _)| Point2::from_row_col(row, col))
+        .collect())
+}
+
+// Draws the elves within the rectangle spanning their positions
+pub fn render_state(state: &State) -> String {
+    let min_row = state.iter().map(|p| p.y).min().unwrap();
+    let max_row = state.iter().map(|p| p.y).max().unwrap();
+    let min_col = state.iter().map(|p| p.x).min().unwrap();
+    let max_col = state.iter().map(|p| p.x).max().unwrap();
+    let n_rows = max_row - min_row + 1;
+    let n_cols = max_col - min_col + 1;
+
+    let mut grid = Grid2D::new(n_rows as usize, n_cols as usize, '.');
+    for elf in state {
+        grid[((elf.y - min_row) as usize, (elf.x - min_col) as usize)] = '#';
+    }
+    format!("{}\nEmpty ground: {}", grid, get_coverage_area(state))
+}
+
+// Get the number of empty tiles in the rectangle spanning the elf positions
+pub fn get_coverage_area(state: &State) -> u32 {
+    let min_row = state.iter().map(|p| p.y).min().unwrap();
+    let max_row = state.iter().map(|p| p.y).max().unwrap();
+    let min_col = state.iter().map(|p| p.x).min().unwrap();
+    let max_col = state.iter().map(|p| p.x).max().unwrap();
+    let n_rows = max_row - min_row + 1;
+    let n_cols = max_col - min_col + 1;
+
+    let rectangle_size = (n_rows * n_cols) as u32;
+    let num_elves = state.len() as u32;
+    rectangle_size - num_elves
+}
+
+// The order the elves consider moving in during the first round
+const INIT_DIRECTIONS: [Direction; 4] = [Direction::North, Direction::South, Direction::West, Direction::East];
+
+// Runs a single round, where the elves consider the directions in the given order
+pub fn simulate_round(cur_state: &State, directions: &[Direction]) -> State {
+    let mut proposed_state = State::new();
+    for &cur_elf in cur_state {
+        // Check for other elves in all directions
+        let is_occupied = |dir: Direction8| cur_state.contains(&(cur_elf + dir.offset()));
+
+        // If no other Elves are in one of those eight positions, the Elf does not do anything during this round.
+        // Otherwise, the Elf looks in each of four directions in the given order
+        // and proposes moving one step in the first valid direction,
+        // if there is no Elf in that direction or diagonally either side of it.
+        let mut proposal = cur_elf;
+        if Direction8::ALL.iter().any(|&dir| is_occupied(dir)) {
+            let free_dir = directions.iter().find(|&&dir| {
+                let dir = Direction8::from(dir);
+                ![dir.rotate(-1), dir, dir.rotate(1)].iter().any(|&d| is_occupied(d))
+            });
+            if let Some(&dir) = free_dir {
+                proposal = cur_elf.step(dir);
+            }
+        }
+        proposed_state.push(proposal);
+    }
+
+    // Now check if each elf can move by checking
+    let mut new_state = State::new();
+    for idx in 0..cur_state.len() {
+        let test_pos = proposed_state[idx];
+        let count = proposed_state.iter()
+                    .filter(|&elem| *elem == test_pos)
+                    .count();
+        if count == 1 {
+            new_state.push(test_pos);
+        } else {
+            new_state.push(cur_state[idx]);
+        }
+    }
+    new_state
+}
+
+// Main simulation function for Part 1
+pub fn simulate(init_state: &State, n_rounds: usize) -> u32 {
+
+    let mut cur_state = init_state.clone();
+    let mut directions = INIT_DIRECTIONS.to_vec();
+
+    animate::frame(|| format!("Initial state\n{}", render_state(&cur_state)));
+    for round in 1..=n_rounds {
+        let new_state = simulate_round(&cur_state, &directions);
+
+        // Update
+        directions.rotate_left(1);
+        cur_state = new_state;
+        animate::frame(|| format!("Round {}\n{}", round, render_state(&cur_state)));
+    }
+    animate::last_frame(|| format!("Round {}\n{}", n_rounds, render_state(&cur_state)));
+
+    get_coverage_area(&cur_state)
+}
+
+// Main simulation function for Part 2
+pub fn simulate_until_done(init_state: &State) -> u32 {
+
+    let mut cur_state = init_state.clone();
+    let mut directions = INIT_DIRECTIONS.to_vec();
+
+    let mut round = 1;
+    animate::frame(|| format!("Initial state\n{}", render_state(&cur_state)));
+    loop {
+        let new_state = simulate_round(&cur_state, &directions);
+
+        // Check if we're done
+        if new_state == cur_state {
+            break;
+        }
+
+        // Update
+        directions.rotate_left(1);
+        cur_state = new_state;
+        animate::frame(|| format!("Round {}\n{}", round, render_state(&cur_state)));
+        round += 1;
+    }
+    animate::last_frame(|| format!("No elf moves in round {}\n{}", round, render_state(&cur_state)));
+
+    round
+}
+
+impl Solution for Day23 {
+    type Input = State;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_data(data)
+    }
+
+    // Part 1
+    fn part1(init_state: &Self::Input) -> Answer {
+        Answer::from(simulate(init_state, 10))
+    }
+
+    // Part 2
+    fn part2(init_state: &Self::Input) -> Answer {
+        Answer::from(simulate_until_done(init_state))
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day23>(filename, part)
+}
@@ -0,0 +1,208 @@
+// Solution to Day 24 puzzle
+// https://adventofcode.com/2022/day/24
+
+use animate;
+use error::{self, ParseError};
+use geometry::{Direction, Point2};
+use grid::Grid2D;
+use search;
+use solution::{self, Answer, Solution};
+
+pub struct Day24;
+
+
+// Handy types
+// Cells are true where there is a wall, or a blizzard
+pub type OccupancyGrid = Grid2D<bool>;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BlizzardState {
+    pub positions: Vec<Point2>,
+    pub directions: Vec<Direction>,
+}
+
+// Parse the data
+pub fn parse_data(data: &str) -> Result<(OccupancyGrid, BlizzardState), ParseError> {
+    error::expect(data.lines().next(), 1, 1, "a map of the valley")?;
+    let map = Grid2D::parse(data, "a wall #, open ground ., or a blizzard", |c| match c {
+        '#' | '.' | '^' | 'v' | '<' | '>' => Some(c),
+        _ => None,
+    })?;
+
+    // Walls are permanent, while the blizzards move
+    let mut grid = OccupancyGrid::new(map.n_rows(), map.n_cols(), false);
+    let mut blizzard_state = BlizzardState {
+        positions: Vec::new(), directions: Vec::new()
+    };
+    for ((row, col), &elem) in map.iter() {
+        if elem == '#' {
+            grid[(row, col)] = true;
+        } else if let Some(dir) = Direction::from_arrow(elem) {
+            blizzard_state.positions.push(Point2::from_row_col(row, col));
+            blizzard_state.directions.push(dir);
+        }
+    }
+
+    Ok((grid, blizzard_state))
+}
+
+// Draws the map with the blizzards, and the expedition as E if given
+pub fn render_state(grid: &OccupancyGrid, blizzard_state: &BlizzardState, expedition: Option<Point2>) -> String {
+    let mut map = Grid2D::new(grid.n_rows(), grid.n_cols(), '.');
+    for (pos, &wall) in grid.iter() {
+        if wall {
+            map[pos] = '#';
+        }
+    }
+    for (pos, dir) in blizzard_state.positions.iter().zip(&blizzard_state.directions) {
+        map[(pos.y as usize, pos.x as usize)] = dir.to_arrow();
+    }
+    if let Some(pos) = expedition {
+        map[(pos.y as usize, pos.x as usize)] = 'E';
+    }
+    map.to_string()
+}
+
+// Gets an occupancy grid given a blizzard state
+pub fn get_grid_at_blizzard_state(grid: &OccupancyGrid, blizzard_state: &BlizzardState) -> OccupancyGrid {
+    let mut new_grid = grid.clone();
+    for pos in blizzard_state.positions.iter() {
+        new_grid[(pos.y as usize, pos.x as usize)] = true;
+    }
+    new_grid
+}
+
+// Steps the blizzard state
+pub fn step_blizzard_state(grid: &OccupancyGrid, blizzard_state: &BlizzardState) -> BlizzardState {
+    let n_rows = grid.n_rows() as i64;
+    let n_cols = grid.n_cols() as i64;
+
+    let mut new_blizzard_state = BlizzardState {
+        positions: Vec::new(),
+        directions: blizzard_state.directions.clone()
+    };
+
+    for (pos, &dir) in blizzard_state.positions.iter().zip(&blizzard_state.directions) {
+        // Step
+        let mut new_pos = pos.step(dir);
+
+        // Wrap around
+        if new_pos.y == 0 {
+            new_pos.y = n_rows - 2;
+        } else if new_pos.y == n_rows - 1 {
+            new_pos.y = 1;
+        }
+        if new_pos.x == 0 {
+            new_pos.x = n_cols - 2;
+        } else if new_pos.x == n_cols - 1 {
+            new_pos.x = 1;
+        }
+
+        new_blizzard_state.positions.push(new_pos);
+    }
+
+    new_blizzard_state
+}
+
+// Search function
+pub fn search_blizzard(grid: &OccupancyGrid,
+                   init_blizzard_state: &BlizzardState,
+                   forward_dir: bool) -> Option<(usize, BlizzardState)> {
+    let n_rows = grid.n_rows();
+    let n_cols = grid.n_cols();
+
+    // Assumes initial and goal positions are always the top left and bottom right of map,
+    // unless the direction is flipped
+    let top_left = Point2::from_row_col(0, 1);
+    let bottom_right = Point2::from_row_col(n_rows - 1, n_cols - 2);
+    let (init_pos, goal_pos) = match forward_dir {
+        true => (top_left, bottom_right),
+        false => (bottom_right, top_left),
+    };
+
+    // Initialize search
+    // Search happens as BFS over (step_number, position) tuples.
+    // When the step number increases, we can step the blizzard state and update the occupancy map.
+    // Initialize a vector of blizzard states and grids
+    let cur_blizzard_state = step_blizzard_state(grid, init_blizzard_state);
+    let mut blizzard_state_vec = Vec::new();
+    blizzard_state_vec.push(cur_blizzard_state.clone());
+    let mut grid_vec = Vec::new();
+    grid_vec.push(get_grid_at_blizzard_state(grid, &cur_blizzard_state));
+
+    let neighbours = |&(cur_step, cur_pos): &(usize, Point2)| {
+        // Get the grid at the current state, creating it if not
+        if cur_step >= grid_vec.len() {
+            let new_blizzard_state = step_blizzard_state(grid, &blizzard_state_vec[cur_step - 1]);
+            grid_vec.push(get_grid_at_blizzard_state(grid, &new_blizzard_state));
+            blizzard_state_vec.push(new_blizzard_state);
+        }
+        let cur_grid = &grid_vec[cur_step];
+
+        // Expand nodes in all possible directions, or do nothing, if possible
+        let moves = Direction::ALL.iter().map(|&dir| cur_pos.step(dir));
+        moves.chain(Some(cur_pos))
+            .filter(|new_pos| cur_grid.get(new_pos.y, new_pos.x) == Some(&false))
+            .map(|new_pos| (cur_step + 1, new_pos))
+            .collect::<Vec<_>>()
+    };
+
+    // Do the search
+    match search::bfs((0, init_pos), neighbours, |&(_, pos)| pos == goal_pos) {
+        Some(path) => {
+            // Replay the way the expedition went
+            if animate::is_enabled() {
+                let render = |&(step, pos): &(usize, Point2)| {
+                    let blizzard_state = if step == 0 { init_blizzard_state } else { &blizzard_state_vec[step - 1] };
+                    format!("Minute {}\n{}", step, render_state(grid, blizzard_state, Some(pos)))
+                };
+                let (last, rest) = path.nodes.split_last().unwrap();
+                for node in rest {
+                    animate::frame(|| render(node));
+                }
+                animate::last_frame(|| format!("{}\nReached the goal", render(last)));
+            }
+            Some((path.cost, blizzard_state_vec[path.cost - 1].clone()))
+        },
+        None => {
+            warn!("Did not find goal");
+            None
+        },
+    }
+}
+
+impl Solution for Day24 {
+    type Input = (OccupancyGrid, BlizzardState);
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse_data(data)
+    }
+
+    // Part 1
+    fn part1((grid, init_blizzard_state): &Self::Input) -> Answer {
+        match search_blizzard(grid, init_blizzard_state, true) {
+            Some((num_steps, _)) => Answer::from(num_steps),
+            None => Answer::None,
+        }
+    }
+
+    // Part 2
+    fn part2((grid, init_blizzard_state): &Self::Input) -> Answer {
+        let trips = search_blizzard(grid, init_blizzard_state, true).and_then(|(steps_there, blizzard_state)| {
+            let (steps_back, blizzard_state) = search_blizzard(grid, &blizzard_state, false)?;
+            let (steps_there_again, _) = search_blizzard(grid, &blizzard_state, true)?;
+            Some((steps_there, steps_back, steps_there_again))
+        });
+        match trips {
+            Some((steps_there, steps_back, steps_there_again)) => {
+                info!("Total steps = {} + {} + {}", steps_there, steps_back, steps_there_again);
+                Answer::from(steps_there + steps_back + steps_there_again)
+            },
+            None => Answer::None,
+        }
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day24>(filename, part)
+}
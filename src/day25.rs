@@ -0,0 +1,81 @@
+// Solution to Day 25 puzzle
+// https://adventofcode.com/2022/day/25
+
+use error::{self, ParseError};
+use solution::{self, Answer, Solution};
+
+pub struct Day25;
+
+pub fn snafu_to_dec(snafu: &str) -> i64 {
+    let len = snafu.len();
+    let mut dec_val: i64 = 0;
+    for (pos, ch) in snafu.chars().enumerate() {
+        let digit: i64 = match ch {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+             _  => {
+                warn!("Bad number, should not happen");
+                0
+             }
+        };
+        let exponent = (len - 1 - pos) as u32;
+        dec_val += i64::pow(5, exponent) * digit; 
+    } 
+    dec_val
+}
+
+pub fn dec_to_snafu(dec: i64) -> String {
+    let mut s = String::from("");
+    let mut quotient = dec;
+    while quotient > 0 {
+        let remainder = (quotient + 2) % 5;
+        quotient = (quotient + 2) / 5;
+        s.push_str(match remainder {
+            4 => "2",
+            3 => "1",
+            2 => "0",
+            1 => "-",
+            0 => "=",
+            _ => "X",
+        });
+    }    
+    s.chars().rev().collect()
+}
+
+pub fn get_fuel_cost(data: &str) -> (i64, String) {
+    let dec_val: i64 = data.lines()
+                            .map(snafu_to_dec)
+                            .sum();
+    (dec_val, dec_to_snafu(dec_val))
+}
+
+impl Solution for Day25 {
+    type Input = String;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        for (i, line) in data.lines().enumerate() {
+            if let Some(col) = line.chars().position(|c| !"210-=".contains(c)) {
+                return Err(ParseError::new(i + 1, col + 1, "a SNAFU digit of 2, 1, 0, -, or ="));
+            }
+        }
+        Ok(data.to_string())
+    }
+
+    fn part1(data: &Self::Input) -> Answer {
+        let (cost_dec, cost_snafu) = get_fuel_cost(data);
+        info!("Fuel cost = {} decimal / {} SNAFU", cost_dec, cost_snafu);
+        Answer::from(cost_snafu)
+    }
+
+    // There is only one part on the last day
+    fn part2(_data: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+pub fn run(filename: &str, part: Option<u32>) -> error::Result<()> {
+    solution::run::<Day25>(filename, part)
+}
@@ -0,0 +1,296 @@
+// Differential testing of the solutions
+//
+// A pair runs a simple reference implementation and the faster one the puzzle
+// uses on the same generated input. Inputs are tried from the smallest size up,
+// so the first one where the answers differ is as easy to debug as possible.
+// A faster variant of a solution can be checked against the old one by adding a pair.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::Range;
+
+use day01::Day01;
+use day03::{self, Day03};
+use day04::{self, Day04};
+use day05::{BoxStacks, Day05, Instruction};
+use day15::{self, Day15};
+use day23::{self, Day23};
+use generate;
+use solution::{Answer, Solution};
+
+pub const DEFAULT_SEEDS: u64 = 20;
+
+pub struct Pair {
+    pub day: u32,
+    pub name: &'static str,
+    pub reference: fn(&str) -> Answer,
+    pub candidate: fn(&str) -> Answer,
+}
+
+pub const PAIRS: &[Pair] = &[
+    Pair { day: 1, name: "most calories", reference: day01_most_naive, candidate: day01_most },
+    Pair { day: 1, name: "top 3 calories", reference: day01_top3_naive, candidate: day01_top3 },
+    Pair { day: 3, name: "rucksack priorities", reference: day03_rucksacks_naive, candidate: day03_rucksacks },
+    Pair { day: 3, name: "badge priorities", reference: day03_badges_naive, candidate: day03_badges },
+    Pair { day: 4, name: "max coverage", reference: day04_max_coverage_naive, candidate: day04_max_coverage },
+    Pair { day: 4, name: "containments", reference: day04_containments_naive, candidate: day04_containments },
+    Pair { day: 5, name: "CrateMover 9000", reference: day05_one_at_a_time_naive, candidate: day05_one_at_a_time },
+    Pair { day: 5, name: "CrateMover 9001", reference: day05_all_at_once_naive, candidate: day05_all_at_once },
+    Pair { day: 15, name: "row coverage", reference: day15_row_naive, candidate: day15_row },
+    Pair { day: 23, name: "elves settle", reference: day23_settled_naive, candidate: day23_settled },
+];
+
+// The pairs for the given days, or all of them if none are given
+pub fn pairs(days: &[u32]) -> Vec<&'static Pair> {
+    PAIRS.iter().filter(|pair| days.is_empty() || days.contains(&pair.day)).collect()
+}
+
+// An input where the two implementations disagree
+#[derive(Debug)]
+pub struct Divergence {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub reference: Answer,
+    pub candidate: Answer,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Reference answer {} but candidate answer {}", self.reference, self.candidate)?;
+        writeln!(f, "for the input with seed {} and size {}:", self.seed, self.size)?;
+        write!(f, "{}", self.input)
+    }
+}
+
+// Runs both implementations on inputs of each size in turn, with each of the seeds,
+// returning the number of inputs they agreed on, or the first where they did not
+pub fn compare(pair: &Pair, seeds: Range<u64>, sizes: Range<usize>) -> Result<usize, Divergence> {
+    let mut num_inputs = 0;
+    for size in sizes {
+        for seed in seeds.clone() {
+            let input = generate::generate(pair.day, seed, size);
+            let reference = (pair.reference)(&input);
+            let candidate = (pair.candidate)(&input);
+            if reference != candidate {
+                return Err(Divergence { seed, size, input, reference, candidate });
+            }
+            num_inputs += 1;
+        }
+    }
+    Ok(num_inputs)
+}
+
+fn parse<S: Solution>(data: &str) -> S::Input {
+    S::parse(data).expect("generated inputs should parse")
+}
+
+// Day 1: sum each elf's block of lines directly
+fn elf_totals(data: &str) -> Vec<i64> {
+    data.trim_end().split("\n\n")
+        .map(|elf| elf.lines().map(|line| line.parse::<i64>().unwrap()).sum())
+        .collect()
+}
+
+fn day01_most_naive(data: &str) -> Answer {
+    Answer::from(elf_totals(data).into_iter().max().unwrap())
+}
+
+fn day01_most(data: &str) -> Answer {
+    Day01::part1(&parse::<Day01>(data))
+}
+
+fn day01_top3_naive(data: &str) -> Answer {
+    let mut totals = elf_totals(data);
+    totals.sort_by(|a, b| b.cmp(a));
+    Answer::from(totals.iter().take(3).sum::<i64>())
+}
+
+fn day01_top3(data: &str) -> Answer {
+    Day01::part2(&parse::<Day01>(data))
+}
+
+// Day 3: look for the first item every part has, one character at a time
+fn first_common_item(parts: &[&str]) -> u32 {
+    parts[0].chars()
+        .find(|&item| parts[1..].iter().all(|part| part.contains(item)))
+        .and_then(day03::get_item_priority)
+        .unwrap_or(0)
+}
+
+fn day03_rucksacks_naive(data: &str) -> Answer {
+    Answer::from(data.lines()
+        .map(|line| {
+            let (left, right) = line.split_at(line.len() / 2);
+            first_common_item(&[left, right])
+        })
+        .sum::<u32>())
+}
+
+fn day03_rucksacks(data: &str) -> Answer {
+    Day03::part1(&parse::<Day03>(data))
+}
+
+fn day03_badges_naive(data: &str) -> Answer {
+    let lines: Vec<&str> = data.lines().collect();
+    Answer::from(lines.chunks(day03::GROUP_SIZE).map(first_common_item).sum::<u32>())
+}
+
+fn day03_badges(data: &str) -> Answer {
+    Day03::part2(&parse::<Day03>(data))
+}
+
+// Day 4: count the elves on each section, and compare every two assignments
+fn day04_max_coverage_naive(data: &str) -> Answer {
+    let elves = day04::assigned(&parse::<Day04>(data));
+    let max = elves.iter()
+        .flat_map(|elf| elf.sections.lo..=elf.sections.hi)
+        .map(|section| elves.iter().filter(|elf| elf.sections.contains(section)).count())
+        .max();
+    Answer::from(max.unwrap_or(0))
+}
+
+fn day04_max_coverage(data: &str) -> Answer {
+    Answer::from(day04::audit_roster(&parse::<Day04>(data)).max_coverage)
+}
+
+fn day04_containments_naive(data: &str) -> Answer {
+    let elves = day04::assigned(&parse::<Day04>(data));
+    let mut num_containments = 0;
+    for (i, a) in elves.iter().enumerate() {
+        for b in &elves[i + 1..] {
+            if a.line != b.line {
+                // Assignments of the same sections contain each other, but only count once
+                if a.sections.contains_interval(&b.sections) || b.sections.contains_interval(&a.sections) {
+                    num_containments += 1;
+                }
+            }
+        }
+    }
+    Answer::from(num_containments)
+}
+
+fn day04_containments(data: &str) -> Answer {
+    Answer::from(day04::audit_roster(&parse::<Day04>(data)).containments.len())
+}
+
+// Day 5: each crane can do the other's job with more moves.
+// The CrateMover 9001 acts like the 9000 if it only ever moves one crate at a time.
+fn day05_one_at_a_time_naive(data: &str) -> Answer {
+    let box_stacks = parse::<Day05>(data);
+    let instructions = box_stacks.instructions.iter()
+        .flat_map(|inst| (0..inst.num).map(move |_| Instruction { num: 1, from: inst.from, to: inst.to }))
+        .collect();
+    let mut box_stacks = BoxStacks { instructions, ..box_stacks };
+    box_stacks.execute(true);
+    Answer::from(box_stacks.get_last_elements())
+}
+
+fn day05_one_at_a_time(data: &str) -> Answer {
+    Day05::part1(&parse::<Day05>(data))
+}
+
+// The CrateMover 9000 keeps the order of the crates it moves if it stacks them
+// on a spare stack first, then moves them on from there
+fn day05_all_at_once_naive(data: &str) -> Answer {
+    let mut box_stacks = parse::<Day05>(data);
+    let spare = box_stacks.crates.len() + 1;
+    box_stacks.crates.push(Vec::new());
+    box_stacks.instructions = box_stacks.instructions.iter()
+        .flat_map(|inst| vec![
+            Instruction { num: inst.num, from: inst.from, to: spare },
+            Instruction { num: inst.num, from: spare, to: inst.to },
+        ])
+        .collect();
+    box_stacks.execute(false);
+    box_stacks.crates.pop();
+    Answer::from(box_stacks.get_last_elements())
+}
+
+fn day05_all_at_once(data: &str) -> Answer {
+    Day05::part2(&parse::<Day05>(data))
+}
+
+// Day 15: check every column of the row against every sensor, one at a time
+fn day15_row_naive(data: &str) -> Answer {
+    let input = parse::<Day15>(data);
+    let y = input.test_row;
+    let reach = |&(sensor, beacon): &day15::Reading| {
+        day15::distance(&sensor, &beacon) as i32 - (sensor.1 - y).abs()
+    };
+    let min_x = input.readings.iter().map(|reading| reading.0 .0 - reach(reading).max(0)).min();
+    let max_x = input.readings.iter().map(|reading| reading.0 .0 + reach(reading).max(0)).max();
+    let (min_x, max_x) = match (min_x, max_x) {
+        (Some(min_x), Some(max_x)) => (min_x, max_x),
+        _ => return Answer::from(0),
+    };
+
+    let num_covered = (min_x..=max_x)
+        .filter(|&x| input.readings.iter().all(|&(_, beacon)| beacon != (x, y)))
+        .filter(|&x| input.readings.iter()
+            .any(|&(sensor, beacon)| day15::distance(&sensor, &(x, y)) <= day15::distance(&sensor, &beacon)))
+        .count();
+    Answer::from(num_covered)
+}
+
+fn day15_row(data: &str) -> Answer {
+    Day15::part1(&parse::<Day15>(data))
+}
+
+// Day 23: a plain round loop over a set of (row, col) positions, read straight from the map,
+// until no elf moves. Gives the number of the round where that happens and the empty ground then.
+fn day23_settled_naive(data: &str) -> Answer {
+    let mut elves: HashSet<(i64, i64)> = data.lines().enumerate()
+        .flat_map(|(row, line)| line.chars().enumerate()
+            .filter(|&(_, c)| c == '#')
+            .map(move |(col, _)| (row as i64, col as i64)))
+        .collect();
+    // The step north, south, west and east, and the cells that need to be free to take it
+    let mut choices = [
+        ((-1, 0), [(-1, -1), (-1, 0), (-1, 1)]),
+        ((1, 0), [(1, -1), (1, 0), (1, 1)]),
+        ((0, -1), [(-1, -1), (0, -1), (1, -1)]),
+        ((0, 1), [(-1, 1), (0, 1), (1, 1)]),
+    ];
+
+    let mut round = 1;
+    loop {
+        let mut proposals: HashMap<(i64, i64), Vec<(i64, i64)>> = HashMap::new();
+        for &(row, col) in &elves {
+            let is_free = |&(d_row, d_col): &(i64, i64)| !elves.contains(&(row + d_row, col + d_col));
+            let neighbours = choices.iter().flat_map(|(_, cells)| cells.iter());
+            if neighbours.clone().all(is_free) {
+                continue;
+            }
+            if let Some(((d_row, d_col), _)) = choices.iter().find(|(_, cells)| cells.iter().all(is_free)) {
+                proposals.entry((row + d_row, col + d_col)).or_default().push((row, col));
+            }
+        }
+        let moves: Vec<((i64, i64), (i64, i64))> = proposals.into_iter()
+            .filter(|(_, from)| from.len() == 1)
+            .map(|(to, from)| (from[0], to))
+            .collect();
+        if moves.is_empty() {
+            break;
+        }
+        for (from, _) in &moves {
+            elves.remove(from);
+        }
+        for (_, to) in moves {
+            elves.insert(to);
+        }
+        choices.rotate_left(1);
+        round += 1;
+    }
+
+    let rows = elves.iter().map(|&(row, _)| row).max().unwrap() - elves.iter().map(|&(row, _)| row).min().unwrap() + 1;
+    let cols = elves.iter().map(|&(_, col)| col).max().unwrap() - elves.iter().map(|&(_, col)| col).min().unwrap() + 1;
+    Answer::from(format!("round {}, {} empty", round, rows * cols - elves.len() as i64))
+}
+
+fn day23_settled(data: &str) -> Answer {
+    let state = parse::<Day23>(data);
+    let num_rounds = day23::simulate_until_done(&state);
+    let empty = day23::simulate(&state, num_rounds as usize - 1);
+    Answer::from(format!("round {}, {} empty", num_rounds, empty))
+}
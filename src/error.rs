@@ -0,0 +1,102 @@
+// Error types shared by all the days
+//
+// Parsers report where in the input they failed and what they expected to find,
+// so a malformed input produces a readable message instead of a panic.
+
+use std::error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // The input file, if known. Filled in by whoever read the file.
+    pub file: Option<String>,
+    // Line and column are 1-indexed
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str) -> Self {
+        ParseError { file: None, line, column, expected: expected.to_string() }
+    }
+
+    pub fn in_file(mut self, filename: &str) -> Self {
+        self.file = Some(filename.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_ref().map_or("<input>", |file| file.as_str());
+        write!(f, "{}:{}:{}: expected {}", file, self.line, self.column, self.expected)
+    }
+}
+
+impl error::Error for ParseError {}
+
+// Any error that can happen while running a day
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Error {
+    // Names the input file in a parse error
+    pub fn in_file(self, filename: &str) -> Self {
+        match self {
+            Error::Parse(e) => Error::Parse(e.in_file(filename)),
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "Parse error: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// Helpers for parsers
+
+// Gets the 1-indexed column of a token that was sliced out of a line
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() { offset + 1 } else { 1 }
+}
+
+// Parses a token from a line, reporting its position if it is not valid
+pub fn parse_token<T: FromStr>(line_num: usize, line: &str, token: &str, expected: &str)
+    -> std::result::Result<T, ParseError> {
+    token.trim().parse::<T>()
+        .map_err(|_| ParseError::new(line_num, column_of(line, token), expected))
+}
+
+// Unwraps a value that must be present at a position in the input
+pub fn expect<T>(value: Option<T>, line_num: usize, column: usize, expected: &str)
+    -> std::result::Result<T, ParseError> {
+    value.ok_or_else(|| ParseError::new(line_num, column, expected))
+}
@@ -0,0 +1,599 @@
+// Random puzzle inputs, for stress-testing the solutions
+//
+// Each generator writes an input in the same format as the puzzle, which is the
+// same for a given seed. The size is roughly the number of lines or items, or the
+// side of a map. The inputs keep to the puzzles' promises where the solutions rely
+// on them, such as there always being a crate left on each stack in day 5.
+
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use rng::Rng;
+
+pub const DEFAULT_SEED: u64 = 1;
+pub const DEFAULT_SIZE: usize = 10;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Generates an input for a day, from 1 to 25
+pub fn generate(day: u32, seed: u64, size: usize) -> String {
+    let rng = &mut Rng::new(seed);
+    let size = max(size, 1);
+    match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 => day16(rng, size),
+        17 => day17(rng, size),
+        18 => day18(rng, size),
+        19 => day19(rng, size),
+        20 => day20(rng, size),
+        21 => day21(rng, size),
+        22 => day22(rng, size),
+        23 => day23(rng, size),
+        24 => day24(rng, size),
+        25 => day25(rng, size),
+        _ => panic!("no puzzle for day {}", day),
+    }
+}
+
+// A word of random letters from the alphabet
+fn word(rng: &mut Rng, alphabet: &str, len: usize) -> String {
+    let letters: Vec<char> = alphabet.chars().collect();
+    (0..len).map(|_| *rng.choose(&letters)).collect()
+}
+
+// Calories carried by each elf, with at least 3 elves for Part 2
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut elves = Vec::new();
+    for _ in 0..max(size, 3) {
+        let num_items = rng.range(1, 6);
+        let items: Vec<String> = (0..num_items).map(|_| rng.range(1000, 60000).to_string()).collect();
+        elves.push(items.join("\n"));
+    }
+    elves.join("\n\n") + "\n"
+}
+
+// Rounds of rock paper scissors
+fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        writeln!(out, "{} {}", rng.choose(&['A', 'B', 'C']), rng.choose(&['X', 'Y', 'Z'])).unwrap();
+    }
+    out
+}
+
+// Groups of 3 rucksacks, where the compartments of each share exactly one item,
+// and the rucksacks of each group share exactly one badge
+fn day03(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.div_ceil(3) {
+        // The badge, then 17 letters for each rucksack that no other one in the group has
+        let mut letters: Vec<char> = LOWERCASE.chars().chain(UPPERCASE.chars()).collect();
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        for pool in letters[1..].chunks(17) {
+            let (shared, left_pool, right_pool) = (pool[0], &pool[1..9], &pool[9..]);
+            let len = rng.range(2, 12) as usize;
+            let mut left: Vec<char> = (0..len).map(|_| *rng.choose(left_pool)).collect();
+            let mut right: Vec<char> = (0..len).map(|_| *rng.choose(right_pool)).collect();
+            let shared_idx = rng.below(len);
+            left[shared_idx] = shared;
+            right[rng.below(len)] = shared;
+            let badge_idx = (shared_idx + 1 + rng.below(len - 1)) % len;
+            left[badge_idx] = badge;
+            if rng.chance(0.5) {
+                // Either compartment can hold the badge
+                std::mem::swap(&mut left, &mut right);
+            }
+            writeln!(out, "{}{}", left.iter().collect::<String>(), right.iter().collect::<String>()).unwrap();
+        }
+    }
+    out
+}
+
+// Pairs of section ranges
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let (min1, min2) = (rng.range(1, 99), rng.range(1, 99));
+        let (max1, max2) = (rng.range(min1, 99), rng.range(min2, 99));
+        writeln!(out, "{}-{},{}-{}", min1, max1, min2, max2).unwrap();
+    }
+    out
+}
+
+// A drawing of stacks of crates and moves between them, which never empty a stack
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let num_stacks = min(3 + size / 10, 9);
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    for _ in 0..num_stacks {
+        let height = rng.range(1, 8) as usize;
+        stacks.push(word(rng, UPPERCASE, height).chars().collect());
+    }
+
+    let mut out = String::new();
+    let max_height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+    for level in (0..max_height).rev() {
+        let row: Vec<String> = stacks.iter()
+            .map(|stack| stack.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+            .collect();
+        writeln!(out, "{}", row.join(" ")).unwrap();
+    }
+    let labels: Vec<String> = (1..=num_stacks).map(|i| format!(" {} ", i)).collect();
+    writeln!(out, "{}\n", labels.join(" ")).unwrap();
+
+    for _ in 0..size {
+        let sources: Vec<usize> = (0..num_stacks).filter(|&i| stacks[i].len() > 1).collect();
+        if sources.is_empty() {
+            break;
+        }
+        let from = *rng.choose(&sources);
+        let to = (from + 1 + rng.below(num_stacks - 1)) % num_stacks;
+        let num_crates = rng.range(1, stacks[from].len() as i64 - 1) as usize;
+        let split = stacks[from].len() - num_crates;
+        let moved = stacks[from].split_off(split);
+        stacks[to].extend(moved);
+        writeln!(out, "move {} from {} to {}", num_crates, from + 1, to + 1).unwrap();
+    }
+    out
+}
+
+// A datastream that ends with 14 different characters, so it always has both markers
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let mut marker: Vec<char> = LOWERCASE.chars().collect();
+    rng.shuffle(&mut marker);
+    let marker: String = marker[..14].iter().collect();
+    format!("{}{}\n", word(rng, LOWERCASE, size * 10), marker)
+}
+
+// The terminal output of exploring a tree of directories, depth first
+fn day07(rng: &mut Rng, size: usize) -> String {
+    // Each directory after the root is inside one of the ones before it
+    let mut names = vec!["/".to_string()];
+    let mut children: Vec<Vec<usize>> = vec![Vec::new()];
+    for dir in 1..size {
+        let parent = rng.below(dir);
+        let len = rng.range(1, 8) as usize;
+        let mut name = word(rng, LOWERCASE, len);
+        while children[parent].iter().any(|&child| names[child] == name) {
+            name.push('x');
+        }
+        names.push(name);
+        children.push(Vec::new());
+        children[parent].push(dir);
+    }
+
+    fn explore(rng: &mut Rng, dir: usize, names: &[String], children: &[Vec<usize>], out: &mut String) {
+        writeln!(out, "$ ls").unwrap();
+        let mut listing: Vec<String> = children[dir].iter().map(|&child| format!("dir {}", names[child])).collect();
+        for i in 0..rng.range(0, 4) {
+            let len = rng.range(1, 8) as usize;
+            let name = format!("{}{}.{}", word(rng, LOWERCASE, len), i, word(rng, LOWERCASE, 3));
+            listing.push(format!("{} {}", rng.range(1000, 400000), name));
+        }
+        rng.shuffle(&mut listing);
+        for line in listing {
+            writeln!(out, "{}", line).unwrap();
+        }
+        for &child in &children[dir] {
+            writeln!(out, "$ cd {}", names[child]).unwrap();
+            explore(rng, child, names, children, out);
+            writeln!(out, "$ cd ..").unwrap();
+        }
+    }
+    let mut out = "$ cd /\n".to_string();
+    explore(rng, 0, &names, &children, &mut out);
+    out
+}
+
+// A square grid of tree heights
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        writeln!(out, "{}", word(rng, "0123456789", size)).unwrap();
+    }
+    out
+}
+
+// Moves of the rope's head
+fn day09(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        writeln!(out, "{} {}", rng.choose(&['U', 'D', 'L', 'R']), rng.range(1, 9)).unwrap();
+    }
+    out
+}
+
+// A program long enough to draw all 240 pixels of the CRT
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let (mut num_lines, mut num_cycles) = (0, 0);
+    while num_lines < size || num_cycles < 240 {
+        if rng.chance(0.3) {
+            writeln!(out, "noop").unwrap();
+            num_cycles += 1;
+        } else {
+            let val = rng.range(1, 15) * if rng.chance(0.5) { 1 } else { -1 };
+            writeln!(out, "addx {}", val).unwrap();
+            num_cycles += 2;
+        }
+        num_lines += 1;
+    }
+    out
+}
+
+// Monkeys that test for different primes, and throw to two other monkeys
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let num_monkeys = min(3 + size / 3, 9);
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+
+    let mut monkeys = Vec::new();
+    for (id, prime) in primes.iter().take(num_monkeys).enumerate() {
+        let items: Vec<String> = (0..rng.range(1, 5)).map(|_| rng.range(50, 99).to_string()).collect();
+        let operation = match rng.below(6) {
+            0 => "old * old".to_string(),
+            1 | 2 => format!("old * {}", rng.range(2, 19)),
+            _ => format!("old + {}", rng.range(1, 8)),
+        };
+        let if_true = (id + 1 + rng.below(num_monkeys - 1)) % num_monkeys;
+        let mut if_false = if_true;
+        while if_false == if_true || if_false == id {
+            if_false = rng.below(num_monkeys);
+        }
+        monkeys.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    \
+             If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            id, items.join(", "), operation, prime, if_true, if_false));
+    }
+    monkeys.join("\n")
+}
+
+// A heightmap that rises from S in the top left to E in the bottom right
+fn day12(rng: &mut Rng, size: usize) -> String {
+    // The slope is gentle enough to climb, but some cells dip below it,
+    // apart from along the top row and down the right side, so there is always a route
+    let (n_rows, n_cols) = (max(size, 5), max(3 * size, 28));
+    let mut out = String::new();
+    for row in 0..n_rows {
+        for col in 0..n_cols {
+            let height = ((row + col) * 25 / (n_rows + n_cols - 2)) as u8;
+            let c = if (row, col) == (0, 0) {
+                'S'
+            } else if (row, col) == (n_rows - 1, n_cols - 1) {
+                'E'
+            } else if height > 0 && row > 0 && col < n_cols - 1 && rng.chance(0.3) {
+                (b'a' + height - 1) as char
+            } else {
+                (b'a' + height) as char
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Pairs of packets of nested lists
+fn day13(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items: Vec<String> = (0..rng.range(0, 4))
+            .map(|_| if depth < 3 && rng.chance(0.3) { packet(rng, depth + 1) } else { rng.range(0, 10).to_string() })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+    let pairs: Vec<String> = (0..size).map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0))).collect();
+    pairs.join("\n")
+}
+
+// Paths of rock below the sand source at 500,0
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    let max_y = min(15 + 2 * size as i64, 170);
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.range(470, 530), rng.range(10, max_y));
+        let mut points = vec![format!("{},{}", x, y)];
+        for i in 0..rng.range(1, 4) {
+            // Alternate between horizontal and vertical lines
+            let len = rng.range(1, 6) * if rng.chance(0.5) { 1 } else { -1 };
+            if i % 2 == 0 {
+                x = (x + len).clamp(400, 600);
+            } else {
+                y = (y + len).clamp(1, max_y);
+            }
+            points.push(format!("{},{}", x, y));
+        }
+        writeln!(out, "{}", points.join(" -> ")).unwrap();
+    }
+    out
+}
+
+// Sensors and their closest beacons, at the scale of the real puzzle
+fn day15(rng: &mut Rng, size: usize) -> String {
+    const MAX_COORD: i64 = 4000000;
+    let mut out = String::new();
+    for _ in 0..size {
+        let (sx, sy) = (rng.range(0, MAX_COORD), rng.range(0, MAX_COORD));
+        let radius = rng.range(1, MAX_COORD / size as i64);
+        let dx = rng.range(-radius, radius);
+        let dy = (radius - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        writeln!(out, "Sensor at x={}, y={}: closest beacon is at x={}, y={}", sx, sy, sx + dx, sy + dy).unwrap();
+    }
+    out
+}
+
+// A connected network of valves, about half of which are stuck at zero flow
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let num_valves = size.clamp(2, 26 * 26);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < num_valves {
+        let name = word(rng, UPPERCASE, 2);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // A random tree keeps every valve reachable, then a few more tunnels make loops
+    let mut tunnels: Vec<HashSet<usize>> = vec![HashSet::new(); num_valves];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for valve in 1..num_valves {
+        connect(valve, rng.below(valve));
+    }
+    for _ in 0..num_valves / 3 {
+        connect(rng.below(num_valves), rng.below(num_valves));
+    }
+
+    let mut lines = Vec::new();
+    for (valve, name) in names.iter().enumerate() {
+        let flow = if valve == 0 || rng.chance(0.5) { 0 } else { rng.range(1, 25) };
+        let mut others: Vec<&str> = tunnels[valve].iter().map(|&other| names[other].as_str()).collect();
+        others.sort();
+        let lead = if others.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+        lines.push(format!("Valve {} has flow rate={}; {} {}\n", name, flow, lead, others.join(", ")));
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+// Jets of gas
+fn day17(rng: &mut Rng, size: usize) -> String {
+    word(rng, "<>", size * 10) + "\n"
+}
+
+// Cubes of lava, kept inside the 20x20x20 space the solution searches
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let mut cubes = HashSet::new();
+    let mut out = String::new();
+    while cubes.len() < min(size, 18 * 18 * 18) {
+        let cube = (rng.range(1, 18), rng.range(1, 18), rng.range(1, 18));
+        if cubes.insert(cube) {
+            writeln!(out, "{},{},{}", cube.0, cube.1, cube.2).unwrap();
+        }
+    }
+    out
+}
+
+// Blueprints for the robots
+fn day19(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        writeln!(out, "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                       Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            id, rng.range(2, 4), rng.range(2, 4), rng.range(2, 4), rng.range(5, 20),
+            rng.range(2, 4), rng.range(5, 20)).unwrap();
+    }
+    out
+}
+
+// Numbers to mix, exactly one of which is 0
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (0..max(size, 2))
+        .map(|_| rng.range(1, 9999) * if rng.chance(0.5) { 1 } else { -1 })
+        .collect();
+    let zero_idx = rng.below(numbers.len());
+    numbers[zero_idx] = 0;
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+// Monkeys yelling numbers and operations, where every division is exact,
+// and humn is only on one side of root, through additions, subtractions and multiplications
+fn day21(rng: &mut Rng, size: usize) -> String {
+    struct Troop {
+        jobs: Vec<String>,
+        names: HashSet<String>,
+        // How many more monkeys can do operations
+        budget: usize,
+    }
+
+    impl Troop {
+        fn name(&mut self, rng: &mut Rng) -> String {
+            loop {
+                let name = word(rng, LOWERCASE, 4);
+                if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                    return name;
+                }
+            }
+        }
+
+        // Adds monkeys that work out the value between them, and gives the name of the one that yells it
+        fn yell(&mut self, rng: &mut Rng, value: i64) -> String {
+            let name = self.name(rng);
+            let job = if self.budget == 0 || rng.chance(0.3) {
+                value.to_string()
+            } else {
+                self.budget -= 1;
+                let divisor = (2..=9).find(|d| value > 0 && value % d == 0);
+                match rng.below(4) {
+                    0 if value >= 2 => {
+                        let left = rng.range(1, value - 1);
+                        format!("{} + {}", self.yell(rng, left), self.yell(rng, value - left))
+                    },
+                    1 if divisor.is_some() => {
+                        let divisor = divisor.unwrap();
+                        format!("{} * {}", self.yell(rng, value / divisor), self.yell(rng, divisor))
+                    },
+                    2 => {
+                        let divisor = rng.range(2, 5);
+                        format!("{} / {}", self.yell(rng, value * divisor), self.yell(rng, divisor))
+                    },
+                    _ => {
+                        let right = rng.range(1, 20);
+                        format!("{} - {}", self.yell(rng, value + right), self.yell(rng, right))
+                    },
+                }
+            };
+            self.jobs.push(format!("{}: {}", name, job));
+            name
+        }
+    }
+
+    let mut troop = Troop { jobs: Vec::new(), names: HashSet::new(), budget: size };
+    let (humn_input, humn_answer) = (rng.range(1, 1000), rng.range(1, 1000));
+    troop.jobs.push(format!("humn: {}", humn_input));
+
+    // Work up from humn, tracking what it leads to when humn has the answer
+    let (mut humn_side, mut value) = ("humn".to_string(), humn_answer);
+    for _ in 0..min(size, 12) {
+        let name = troop.name(rng);
+        let job = match rng.below(4) {
+            0 => {
+                let other = rng.range(2, 9);
+                value *= other;
+                format!("{} * {}", humn_side, troop.yell(rng, other))
+            },
+            1 => {
+                let other = rng.range(1, 99);
+                value -= other;
+                format!("{} - {}", humn_side, troop.yell(rng, other))
+            },
+            2 => {
+                let other = rng.range(1, 99);
+                value = other - value;
+                format!("{} - {}", troop.yell(rng, other), humn_side)
+            },
+            _ => {
+                let other = rng.range(1, 99);
+                value += other;
+                format!("{} + {}", troop.yell(rng, other), humn_side)
+            },
+        };
+        troop.jobs.push(format!("{}: {}", name, job));
+        humn_side = name;
+    }
+
+    // Root only checks that both sides are equal in Part 2
+    let other_side = troop.yell(rng, value);
+    if rng.chance(0.5) {
+        troop.jobs.push(format!("root: {} + {}", humn_side, other_side));
+    } else {
+        troop.jobs.push(format!("root: {} + {}", other_side, humn_side));
+    }
+    rng.shuffle(&mut troop.jobs);
+    troop.jobs.iter().map(|job| format!("{}\n", job)).collect()
+}
+
+// A map folded from the same cube net as the real puzzle, with faces of the given size,
+// and a path over it. A size of 50 gives a map that Part 2 can fold.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    const FACES: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+    let face_size = max(size, 2);
+    let mut out = String::new();
+    for row in 0..4 * face_size {
+        let mut line = String::new();
+        for col in 0..3 * face_size {
+            let c = if !FACES.contains(&(row / face_size, col / face_size)) {
+                ' '
+            } else if (row, col) != (0, face_size) && rng.chance(0.1) {
+                '#'
+            } else {
+                '.'
+            };
+            line.push(c);
+        }
+        writeln!(out, "{}", line.trim_end()).unwrap();
+    }
+
+    let mut path = rng.range(1, 2 * face_size as i64).to_string();
+    for _ in 0..2 * size {
+        write!(path, "{}{}", rng.choose(&['L', 'R']), rng.range(1, 2 * face_size as i64)).unwrap();
+    }
+    format!("{}\n{}\n", out, path)
+}
+
+// A square grove with at least one elf in it
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let mut grove: Vec<Vec<char>> = (0..size)
+        .map(|_| (0..size).map(|_| if rng.chance(0.4) { '#' } else { '.' }).collect())
+        .collect();
+    // There is always at least one elf
+    if !grove.iter().flatten().any(|&c| c == '#') {
+        grove[rng.below(size)][rng.below(size)] = '#';
+    }
+    grove.iter().map(|row| format!("{}\n", row.iter().collect::<String>())).collect()
+}
+
+// A valley with blizzards, none of which blow up or down the entrance and exit columns
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let (n_rows, n_cols) = (max(size / 2, 2), max(size, 3));
+    let mut out = format!("#.{}\n", "#".repeat(n_cols));
+    for _ in 0..n_rows {
+        let mut line = "#".to_string();
+        for col in 0..n_cols {
+            let c = if !rng.chance(0.2) {
+                '.'
+            } else if col == 0 || col == n_cols - 1 {
+                *rng.choose(&['<', '>'])
+            } else {
+                *rng.choose(&['<', '>', '^', 'v'])
+            };
+            line.push(c);
+        }
+        writeln!(out, "{}#", line).unwrap();
+    }
+    writeln!(out, "{}.#", "#".repeat(n_cols)).unwrap();
+    out
+}
+
+// Fuel requirements in SNAFU
+fn day25(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{}\n", to_snafu(rng.range(1, 1_000_000_000_000)))).join("")
+}
+
+// Writes a number in balanced base 5, with digits =, -, 0, 1 and 2
+fn to_snafu(mut val: i64) -> String {
+    let mut digits = Vec::new();
+    while val != 0 {
+        let digit = (val + 2).rem_euclid(5) - 2;
+        digits.push(match digit {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            _ => '2',
+        });
+        val = (val - digit) / 5;
+    }
+    digits.iter().rev().collect()
+}
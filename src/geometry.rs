@@ -0,0 +1,260 @@
+// Points, directions and turns for the puzzles that move things around
+//
+// Points use screen coordinates, the same as a Grid2D, so y grows downwards:
+//
+//   o---> x
+//   |
+//   v
+//   y
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    // The point at a (row, col) position on a grid
+    pub fn from_row_col(row: usize, col: usize) -> Self {
+        Point2 { x: col as i64, y: row as i64 }
+    }
+
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // Each coordinate reduced to -1, 0 or 1
+    pub fn signum(self) -> Self {
+        Point2 { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.iter().map(move |&dir| self + dir.offset())
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Direction8::ALL.iter().map(move |&dir| self + dir.offset())
+    }
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    // The 6 points sharing a face with this one
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        const OFFSETS: [Point3; 6] = [
+            Point3 { x: 1, y: 0, z: 0 }, Point3 { x: -1, y: 0, z: 0 },
+            Point3 { x: 0, y: 1, z: 0 }, Point3 { x: 0, y: -1, z: 0 },
+            Point3 { x: 0, y: 0, z: 1 }, Point3 { x: 0, y: 0, z: -1 },
+        ];
+        OFFSETS.iter().map(move |&offset| self + offset)
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl Add for $point {
+            type Output = $point;
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                $(self.$field += other.$field;)*
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                $(self.$field -= other.$field;)*
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+            fn mul(self, scale: i64) -> $point {
+                $point { $($field: self.$field * scale),* }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),* }
+            }
+        }
+    };
+}
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// The 4 directions along the grid, where north is up
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+// The 8 directions including the diagonals
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+}
+
+impl Direction {
+    // Clockwise from north
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::North => Point2::new(0, -1),
+            Direction::East => Point2::new(1, 0),
+            Direction::South => Point2::new(0, 1),
+            Direction::West => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        let quarters = match turn {
+            Turn::Right => 1,
+            Turn::Around => 2,
+            Turn::Left => 3,
+        };
+        Direction::ALL[(self as usize + quarters) % 4]
+    }
+
+    // Parses the arrows used on maps: ^, >, v and <
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+
+    // Parses the letters used for moves: U, R, D and L
+    pub fn from_udlr(s: &str) -> Option<Self> {
+        match s {
+            "U" => Some(Direction::North),
+            "R" => Some(Direction::East),
+            "D" => Some(Direction::South),
+            "L" => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+impl Direction8 {
+    // Clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North, Direction8::NorthEast, Direction8::East, Direction8::SouthEast,
+        Direction8::South, Direction8::SouthWest, Direction8::West, Direction8::NorthWest,
+    ];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction8::North => Point2::new(0, -1),
+            Direction8::NorthEast => Point2::new(1, -1),
+            Direction8::East => Point2::new(1, 0),
+            Direction8::SouthEast => Point2::new(1, 1),
+            Direction8::South => Point2::new(0, 1),
+            Direction8::SouthWest => Point2::new(-1, 1),
+            Direction8::West => Point2::new(-1, 0),
+            Direction8::NorthWest => Point2::new(-1, -1),
+        }
+    }
+
+    // Rotates clockwise by the given number of eighths of a turn, or counterclockwise if negative
+    pub fn rotate(self, eighths: i32) -> Self {
+        Direction8::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+impl Turn {
+    // Parses the L and R used in paths
+    pub fn from_lr(s: &str) -> Option<Self> {
+        match s {
+            "L" => Some(Turn::Left),
+            "R" => Some(Turn::Right),
+            _ => None,
+        }
+    }
+}
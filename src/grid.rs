@@ -0,0 +1,203 @@
+// A 2D grid of cells, for the puzzles whose input is a map of characters
+//
+// Cells are indexed by (row, col) with the origin at the upper left:
+//
+//   o---> col
+//   |
+//   v
+//   row
+
+use std::cmp::{max, min};
+use std::convert::TryInto;
+use std::fmt;
+use std::ops::{Index, IndexMut, Range};
+
+use ndarray::{s, Array2, ArrayView1};
+
+use error::ParseError;
+
+// Offsets to the neighbours of a cell, as (row, col)
+const NEIGHBOURS_4: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid2D<T> {
+    pub cells: Array2<T>,
+}
+
+impl<T> Grid2D<T> {
+    pub fn new(n_rows: usize, n_cols: usize, fill: T) -> Self where T: Clone {
+        Grid2D { cells: Array2::from_elem((n_rows, n_cols), fill) }
+    }
+
+    // Parses a map with one character per cell, where every row must have the same length.
+    // The cell function gives None for characters that are not valid cells.
+    pub fn parse<F>(data: &str, expected: &str, cell: F) -> Result<Self, ParseError>
+        where F: FnMut(char) -> Option<T> {
+        Self::parse_rows(data, None::<fn() -> T>, expected, cell)
+    }
+
+    // Parses a map whose shorter rows are padded at the end with the fill cell
+    pub fn parse_padded<F>(data: &str, fill: T, expected: &str, cell: F) -> Result<Self, ParseError>
+        where T: Clone, F: FnMut(char) -> Option<T> {
+        Self::parse_rows(data, Some(|| fill.clone()), expected, cell)
+    }
+
+    fn parse_rows<F, G>(data: &str, mut fill: Option<G>, expected: &str, mut cell: F) -> Result<Self, ParseError>
+        where F: FnMut(char) -> Option<T>, G: FnMut() -> T {
+        let lines: Vec<&str> = data.lines().collect();
+        let n_cols = match fill {
+            Some(_) => lines.iter().map(|line| line.chars().count()).max().unwrap_or(0),
+            None => lines.first().map_or(0, |line| line.chars().count()),
+        };
+        let mut cells = Vec::with_capacity(lines.len() * n_cols);
+        for (row, line) in lines.iter().enumerate() {
+            let mut len = 0;
+            for (col, c) in line.chars().enumerate() {
+                if col >= n_cols {
+                    return Err(ParseError::new(row + 1, col + 1, &format!("rows of the same length {}", n_cols)));
+                }
+                cells.push(cell(c).ok_or_else(|| ParseError::new(row + 1, col + 1, expected))?);
+                len += 1;
+            }
+            match fill {
+                Some(ref mut fill) => cells.extend((len..n_cols).map(|_| fill())),
+                None if len < n_cols => {
+                    return Err(ParseError::new(row + 1, len + 1, &format!("rows of the same length {}", n_cols)));
+                },
+                None => (),
+            }
+        }
+        let cells = Array2::from_shape_vec((lines.len(), n_cols), cells)
+            .expect("the cells fill every row");
+        Ok(Grid2D { cells })
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    // Checks that a position, which may be negative, is on the grid
+    pub fn contains<I: TryInto<usize>>(&self, row: I, col: I) -> bool {
+        self.index_of(row, col).is_some()
+    }
+
+    fn index_of<I: TryInto<usize>>(&self, row: I, col: I) -> Option<(usize, usize)> {
+        let row = row.try_into().ok()?;
+        let col = col.try_into().ok()?;
+        if row < self.n_rows() && col < self.n_cols() { Some((row, col)) } else { None }
+    }
+
+    // Gets a cell, or None if the position is off the grid
+    pub fn get<I: TryInto<usize>>(&self, row: I, col: I) -> Option<&T> {
+        let idx = self.index_of(row, col)?;
+        self.cells.get(idx)
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, row: I, col: I) -> Option<&mut T> {
+        let idx = self.index_of(row, col)?;
+        self.cells.get_mut(idx)
+    }
+
+    // The positions up, right, down and left of a cell that are on the grid
+    pub fn neighbours4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(row, col, &NEIGHBOURS_4)
+    }
+
+    // The positions of the 8 cells surrounding a cell that are on the grid,
+    // clockwise from the one above it
+    pub fn neighbours8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(row, col, &NEIGHBOURS_8)
+    }
+
+    fn neighbours(&self, row: usize, col: usize, offsets: &'static [(i64, i64)])
+        -> impl Iterator<Item = (usize, usize)> {
+        let (n_rows, n_cols) = (self.n_rows() as i64, self.n_cols() as i64);
+        offsets.iter()
+            .map(move |&(dr, dc)| (row as i64 + dr, col as i64 + dc))
+            .filter(move |&(r, c)| r >= 0 && r < n_rows && c >= 0 && c < n_cols)
+            .map(|(r, c)| (r as usize, c as usize))
+    }
+
+    pub fn row(&self, row: usize) -> ArrayView1<'_, T> {
+        self.cells.row(row)
+    }
+
+    pub fn col(&self, col: usize) -> ArrayView1<'_, T> {
+        self.cells.column(col)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.rows().into_iter()
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.columns().into_iter()
+    }
+
+    // All the cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.indexed_iter()
+    }
+
+    // The position of the first cell, row by row, that matches
+    pub fn find<P: Fn(&T) -> bool>(&self, pred: P) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    // A grid of the same shape with each cell converted
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid2D<U> {
+        Grid2D { cells: self.cells.map(f) }
+    }
+
+    // The smallest rows and columns that hold all the cells that match
+    pub fn bounding_box<P: Fn(&T) -> bool>(&self, pred: P) -> Option<(Range<usize>, Range<usize>)> {
+        self.iter()
+            .filter(|(_, cell)| pred(cell))
+            .fold(None, |bounds, ((row, col), _)| match bounds {
+                None => Some((row..row + 1, col..col + 1)),
+                Some((rows, cols)) => Some((min(rows.start, row)..max(rows.end, row + 1),
+                                            min(cols.start, col)..max(cols.end, col + 1))),
+            })
+    }
+
+    // A copy of part of the grid
+    pub fn crop(&self, rows: Range<usize>, cols: Range<usize>) -> Self where T: Clone {
+        Grid2D { cells: self.cells.slice(s![rows, cols]).to_owned() }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid2D<T> {
+    type Output = T;
+
+    fn index(&self, idx: (usize, usize)) -> &T {
+        &self.cells[idx]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2D<T> {
+    fn index_mut(&mut self, idx: (usize, usize)) -> &mut T {
+        &mut self.cells[idx]
+    }
+}
+
+// Writes each cell as it displays, one line per row
+impl<T: fmt::Display> fmt::Display for Grid2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row.iter() {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+// Images of grids in the netpbm formats
+//
+// PGM (greyscale) and PPM (colour) images are simple enough to write without any
+// libraries, and most image viewers can open them. Each cell of a grid becomes a
+// square block of pixels. The days map their cells to pixels with their own palette,
+// and snapshots are only written when a directory is given with --dump-frames.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use grid::Grid2D;
+
+pub type Rgb = [u8; 3];
+
+static DUMP_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Writes a greyscale image, where 0 is black and 255 is white
+pub fn write_pgm<W: Write>(out: &mut W, image: &Grid2D<u8>, scale: usize) -> io::Result<()> {
+    write_pixels(out, "P5", image, scale, |&shade| [shade])
+}
+
+pub fn write_ppm<W: Write>(out: &mut W, image: &Grid2D<Rgb>, scale: usize) -> io::Result<()> {
+    write_pixels(out, "P6", image, scale, |&rgb| rgb)
+}
+
+fn write_pixels<T, W, F, P>(out: &mut W, magic: &str, image: &Grid2D<T>, scale: usize, pixel: F) -> io::Result<()>
+    where W: Write, F: Fn(&T) -> P, P: AsRef<[u8]> {
+    assert!(scale > 0, "images need a scale of at least 1");
+    write!(out, "{}\n{} {}\n255\n", magic, image.n_cols() * scale, image.n_rows() * scale)?;
+    for row in image.rows() {
+        let mut line = Vec::new();
+        for cell in row.iter() {
+            let pixel = pixel(cell);
+            for _ in 0..scale {
+                line.extend_from_slice(pixel.as_ref());
+            }
+        }
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+// Sets the directory that snapshots are written to, or stops writing them
+pub fn set_dump_dir(dir: Option<PathBuf>) {
+    *DUMP_DIR.lock().unwrap() = dir;
+}
+
+pub fn is_dumping() -> bool {
+    DUMP_DIR.lock().unwrap().is_some()
+}
+
+// Writes a greyscale snapshot to name.pgm in the dump directory, if there is one
+pub fn dump_pgm(name: &str, image: &Grid2D<u8>, scale: usize) {
+    dump(name, "pgm", |out| write_pgm(out, image, scale));
+}
+
+// Writes a colour snapshot to name.ppm in the dump directory, if there is one
+pub fn dump_ppm(name: &str, image: &Grid2D<Rgb>, scale: usize) {
+    dump(name, "ppm", |out| write_ppm(out, image, scale));
+}
+
+fn dump<F>(name: &str, extension: &str, write: F)
+    where F: FnOnce(&mut BufWriter<File>) -> io::Result<()> {
+    let path = match *DUMP_DIR.lock().unwrap() {
+        Some(ref dir) => dir.join(format!("{}.{}", name, extension)),
+        None => return,
+    };
+    // A snapshot is not worth failing the puzzle over
+    let result = File::create(&path)
+        .map(BufWriter::new)
+        .and_then(|mut out| write(&mut out).and_then(|_| out.flush()));
+    match result {
+        Ok(()) => info!("Wrote {}", path.display()),
+        Err(e) => warn!("Could not write {}: {}", path.display(), e),
+    }
+}
@@ -0,0 +1,166 @@
+// Inclusive integer intervals, and sets of them
+//
+// An IntervalSet keeps its intervals sorted and merged, so its size and gaps
+// can be found from the few intervals rather than every value they cover.
+
+use std::cmp::{max, min};
+use std::fmt;
+use std::iter::FromIterator;
+
+// The integers from lo to hi, including both ends
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub lo: i64,
+    pub hi: i64,
+}
+
+impl Interval {
+    // Panics if the interval would be empty
+    pub fn new(lo: i64, hi: i64) -> Self {
+        assert!(lo <= hi, "empty interval {}..={}", lo, hi);
+        Interval { lo, hi }
+    }
+
+    // The number of integers in the interval, which is never empty
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> i64 {
+        self.hi - self.lo + 1
+    }
+
+    pub fn contains(&self, val: i64) -> bool {
+        self.lo <= val && val <= self.hi
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval::new(max(self.lo, other.lo), min(self.hi, other.hi)))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.lo, self.hi)
+    }
+}
+
+// A set of integers, stored as sorted intervals that neither overlap nor touch
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    // Adds an interval, merging it with any it overlaps or touches
+    pub fn insert(&mut self, interval: Interval) {
+        // The intervals from first to last are the ones to merge with
+        let first = self.intervals.partition_point(|i| i.hi + 1 < interval.lo);
+        let last = self.intervals.partition_point(|i| i.lo <= interval.hi + 1);
+        let mut merged = interval;
+        if first < last {
+            merged.lo = min(merged.lo, self.intervals[first].lo);
+            merged.hi = max(merged.hi, self.intervals[last - 1].hi);
+        }
+        self.intervals.splice(first..last, Some(merged));
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    // The number of integers in the set
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, val: i64) -> bool {
+        self.interval_at(val).is_some()
+    }
+
+    pub fn contains_interval(&self, interval: &Interval) -> bool {
+        self.interval_at(interval.lo).is_some_and(|i| i.contains_interval(interval))
+    }
+
+    fn interval_at(&self, val: i64) -> Option<&Interval> {
+        let idx = self.intervals.partition_point(|i| i.hi < val);
+        self.intervals.get(idx).filter(|i| i.contains(val))
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (&self.intervals[a], &other.intervals[b]);
+            intervals.extend(x.intersection(y));
+            // Move on from whichever ends first, as it can't overlap anything else
+            if x.hi < y.hi {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    // The intervals within the bounds that are not in the set
+    pub fn gaps(&self, bounds: &Interval) -> Vec<Interval> {
+        let mut gaps = Vec::new();
+        let mut next = bounds.lo;
+        for interval in &self.intervals {
+            if interval.hi < next {
+                continue;
+            }
+            if interval.lo > bounds.hi {
+                break;
+            }
+            if interval.lo > next {
+                gaps.push(Interval::new(next, interval.lo - 1));
+            }
+            next = interval.hi + 1;
+        }
+        if next <= bounds.hi {
+            gaps.push(Interval::new(next, bounds.hi));
+        }
+        gaps
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        Ok(())
+    }
+}
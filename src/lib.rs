@@ -0,0 +1,57 @@
+// Advent of Code 2022 solutions
+//
+// Each day's solution lives in its own module so it can be used by the
+// per-day binaries, the `aoc` runner, and tests alike.
+
+extern crate evalexpr;
+extern crate itertools;
+extern crate ndarray;
+extern crate num;
+extern crate rayon;
+extern crate regex;
+
+// Declared first so the logging macros can be used in all the other modules
+#[macro_use]
+pub mod log;
+
+pub mod animate;
+pub mod bench;
+pub mod cycle;
+pub mod differential;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod interval;
+pub mod output;
+pub mod registry;
+pub mod rng;
+pub mod search;
+pub mod solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
@@ -0,0 +1,73 @@
+// Diagnostic output, at a level set from the command line
+//
+// The days explain their working on stderr through the warn!, info! and trace!
+// macros, so the answers on stdout are unaffected. Only warnings are printed
+// by default, -v adds a summary of each day's working, -vv adds every step,
+// and -q hides the warnings as well. The message is only formatted when it
+// is printed, so trace! can be left in the hot loops.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Warn,
+    Info,
+    Trace,
+}
+
+const LEVELS: [Level; 4] = [Level::Quiet, Level::Warn, Level::Info, Level::Trace];
+
+static LEVEL: AtomicUsize = AtomicUsize::new(Level::Warn as usize);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as usize, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    LEVELS[LEVEL.load(Ordering::Relaxed)]
+}
+
+// Whether messages at this level are printed
+pub fn enabled(level: Level) -> bool {
+    level != Level::Quiet && level <= self::level()
+}
+
+// The level for the number of times -v is given, or quiet for -q
+pub fn from_flags(quiet: bool, verbosity: usize) -> Level {
+    if quiet {
+        Level::Quiet
+    } else {
+        LEVELS[(Level::Warn as usize + verbosity).min(Level::Trace as usize)]
+    }
+}
+
+// Something looks wrong with the input or the answer
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Warn) {
+            eprintln!("WARNING: {}", format_args!($($arg)*));
+        }
+    };
+}
+
+// A summary of how the answer was reached
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+// Each step of the working
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -1,3 +1,709 @@
+// Advent of Code 2022 runner
+//
+// Dispatches to any day's solution in the library through a common entry point.
+//
+// Usage:
+//   cargo run --bin aoc run <day> [part] [input] [extra] [--format text|json|csv]
+//                           [--animate [fps]] [--frame-skip N] [--dump-frames dir]
+//                           [-q | -v | -vv] [--search [--top K]] [--group-size K] [--audit]
+//   cargo run -r --bin aoc bench <day> [part] [input] [extra] [--runs N]
+//   cargo run -r --bin aoc all [input name] [--format text|json|csv]
+//   cargo run -r --bin aoc verify [day...] [--answers file]
+//   cargo run -r --bin aoc record [day...] [--input name] [--answers file] [--force]
+//   cargo run --bin aoc gen <day> [--seed N] [--size S]
+//   cargo run -r --bin aoc diff [day...] [--seeds N] [--size S]
+//
+// By default only warnings are printed to stderr. -v also explains how each answer was reached,
+// -vv prints every step of the working, and -q prints nothing but the answers.
+// The part can be given as 1/2 or a/b, and is optional for the days that solve both parts.
+// The input can be "-" to read from stdin. If no input is given, stdin is used when
+// something is piped in, otherwise the day's test input.
+//
+// Example usage:
+//   cargo run --bin aoc run 1 data/day01/puzzle_input.txt
+//   cargo run --bin aoc run 5 b data/day05/test_input.txt
+//   cargo run -r --bin aoc run 1 2 data/day01/puzzle_input.txt 10
+//   cargo run --bin aoc run 2 data/day02/rpsls_test_input.txt data/day02/rpsls_rules.txt
+//   cargo run --bin aoc run 2 data/day02/puzzle_input.txt --search
+//   cargo run --bin aoc run 2 data/day02/rpsls_test_input.txt data/day02/rpsls_rules.txt --search --top 3
+//   cargo run -r --bin aoc run 3 2 data/day03/puzzle_input.txt --group-size 6
+//   cargo run --bin aoc run 4 data/day04/puzzle_input.txt --audit
+//   cargo run -r --bin aoc run 15 1 data/day15/puzzle_input.txt 2000000
+//   cargo run --bin aoc run 13 data/day13/test_input.txt -vv
+//   cat data/day01/puzzle_input.txt | cargo run --bin aoc run 1
+//   cargo run -r --bin aoc run 20 data/day20/puzzle_input.txt --format json
+//   cargo run -r --bin aoc bench 20 data/day20/puzzle_input.txt --runs 5
+//   cargo run -r --bin aoc run 14 2 data/day14/puzzle_input.txt --animate 60 --frame-skip 50
+//   cargo run -r --bin aoc run 22 data/day22/puzzle_input.txt --dump-frames frames
+//   cargo run -r --bin aoc all
+//   cargo run -r --bin aoc all test_input.txt
+//   cargo run -r --bin aoc verify 1 2 3
+//   cargo run -r --bin aoc record 25
+//   cargo run --bin aoc gen 12 --seed 7 --size 40 > big_hill.txt
+//   cargo run -r --bin aoc diff 5 15 --seeds 100
+
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+extern crate advent_of_code_2022;
+extern crate rayon;
+use advent_of_code_2022::*;
+use advent_of_code_2022::bench::Bench;
+use advent_of_code_2022::output::{DayResult, Format, Record};
+use advent_of_code_2022::registry::{self, Registry};
+use advent_of_code_2022::solution::{Answer, Solution};
+use rayon::prelude::*;
+
+// Number of times each part is run by the bench command, by default
+const DEFAULT_RUNS: usize = 10;
+
+enum Command {
+    Run(Format),
+    Bench(usize),
+}
+
+enum Report {
+    Answers(Vec<Record>),
+    Bench(Bench),
+}
+
+fn print_usage() {
+    eprintln!("Usage: aoc run <day> [part] [input] [extra] [--format text|json|csv]");
+    eprintln!("                [--animate [fps]] [--frame-skip N] [--dump-frames dir] [-q | -v | -vv]");
+    eprintln!("                [--search [--top K]] [--group-size K] [--audit]");
+    eprintln!("       aoc bench <day> [part] [input] [extra] [--runs N]");
+    eprintln!("       aoc all [input name] [--format text|json|csv]");
+    eprintln!("       aoc verify [day...] [--answers file]");
+    eprintln!("       aoc record [day...] [--input name] [--answers file] [--force]");
+    eprintln!("       aoc gen <day> [--seed N] [--size S]");
+    eprintln!("       aoc diff [day...] [--seeds N] [--size S]");
+    eprintln!("  <day>    Day number from 1 to 25");
+    eprintln!("  [part]   1/2 or a/b (default: both parts)");
+    eprintln!("  [input]  Puzzle input file, or - for stdin (default: stdin if piped, else the day's test input)");
+    eprintln!("  [extra]  Day-specific argument (Day 1 number of top elves / Day 2 rules file /");
+    eprintln!("           Day 3 group size / Day 15 row / search limit)");
+    eprintln!("  [input name] File to run in each day's data directory (default: puzzle_input.txt)");
+    eprintln!("  -v, -vv  Explain how each answer was reached, or print every step (default: only warnings)");
+    eprintln!("  -q       Print nothing but the answers, not even warnings");
+    eprintln!("  --format Output format (default: text). json and csv print only the answer records,");
+    eprintln!("           with the time each part took in seconds.");
+    eprintln!("  --runs   Number of times to run each part when benchmarking (default: {})", DEFAULT_RUNS);
+    eprintln!("  --animate Redraw the simulation in the terminal for days 14, 17, 23 and 24,");
+    eprintln!("           at the given frames per second (default: {})", animate::DEFAULT_FPS);
+    eprintln!("  --frame-skip Only draw every Nth step of the animation (default: 1)");
+    eprintln!("  --dump-frames Write images of days 8, 12, 14, 17 and 22 to the directory");
+    eprintln!("  --search Score the Day 2 guide under every meaning of the second column, ranked,");
+    eprintln!("           and show how the best one scores each round");
+    eprintln!("  --top    Number of meanings --search shows (default: {})", day02::DEFAULT_TOP);
+    eprintln!("  --group-size Number of elves in each Day 3 group (default: {})", day03::GROUP_SIZE);
+    eprintln!("  --audit  Sweep over every Day 4 assignment, showing how many elves cover each section,");
+    eprintln!("           the sections nobody covers, and every assignment containing one on another line");
+    eprintln!("  [day...] Days to verify, record or diff (default: all of them)");
+    eprintln!("  --answers Registry of known answers (default: {})", registry::DEFAULT_PATH);
+    eprintln!("  --input  File to record in each day's data directory (default: puzzle_input.txt)");
+    eprintln!("  --force  Record answers again for days that already have them");
+    eprintln!("  --seed   Seed of the generated input, which is the same for the same seed (default: {})",
+              generate::DEFAULT_SEED);
+    eprintln!("  --size   Rough number of lines or items in the generated input (default: {}).", generate::DEFAULT_SIZE);
+    eprintln!("           diff tries each size from 1 up to this one.");
+    eprintln!("  --seeds  Number of inputs of each size that diff tries (default: {})", differential::DEFAULT_SEEDS);
+}
+
+// Removes an option given as "--name value" or "--name=value" from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let prefix = format!("{}=", name);
+    let idx = args.iter().position(|arg| arg == name || arg.starts_with(&prefix))?;
+    let arg = args.remove(idx);
+    if arg == name {
+        if idx >= args.len() {
+            usage_error(&format!("Missing value for {}", name));
+        }
+        Some(args.remove(idx))
+    } else {
+        Some(arg[prefix.len()..].to_string())
+    }
+}
+
+// Removes "--animate" from the arguments, along with the frame rate if it follows,
+// returning the frame rate
+fn take_animate(args: &mut Vec<String>) -> Option<f64> {
+    let idx = args.iter().position(|arg| arg == "--animate" || arg.starts_with("--animate="))?;
+    let arg = args.remove(idx);
+    let fps = match arg.strip_prefix("--animate=") {
+        Some(val) => Some(val.to_string()),
+        // The frame rate is optional, so only take the next argument if it is a number
+        None if args.get(idx).is_some_and(|val| val.parse::<f64>().is_ok()) => Some(args.remove(idx)),
+        None => None,
+    };
+    match fps.map(|val| val.parse::<f64>()) {
+        Some(Ok(fps)) if fps > 0.0 && fps.is_finite() => Some(fps),
+        Some(_) => usage_error("The frame rate must be a positive number"),
+        None => Some(animate::DEFAULT_FPS),
+    }
+}
+
+// Removes a flag from the arguments, returning whether it was there
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    match args.iter().position(|arg| arg == name) {
+        Some(idx) => {
+            args.remove(idx);
+            true
+        },
+        None => false,
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n", message);
+    print_usage();
+    process::exit(2);
+}
+
+// Accepts "7", "07", or "day07"
+fn parse_day(arg: &str) -> Option<u32> {
+    let day = arg.trim_start_matches("day").parse::<u32>().ok()?;
+    if (1..=25).contains(&day) { Some(day) } else { None }
+}
+
+fn parse_part(arg: &str) -> Option<u32> {
+    match arg {
+        "1" | "a" => Some(1),
+        "2" | "b" => Some(2),
+        _ => None,
+    }
+}
+
+// Reads from stdin if something is piped in, otherwise uses the day's test input
+fn default_input(day: u32) -> String {
+    if !io::stdin().is_terminal() {
+        return solution::STDIN.to_string();
+    }
+    match day {
+        9 => "data/day09/test_input_1.txt".to_string(),
+        _ => format!("data/day{:02}/test_input.txt", day),
+    }
+}
+
+// The file with this name in a day's data directory. Days with more than one
+// example, like day 9, number them from 1, so the first one stands in for the name.
+fn day_input(day: u32, input_name: &str) -> String {
+    let path = PathBuf::from(format!("data/day{:02}", day)).join(input_name);
+    if !path.exists() {
+        if let (Some(stem), Some(ext)) = (path.file_stem(), path.extension()) {
+            let first = path.with_file_name(format!("{}_1.{}", stem.to_string_lossy(), ext.to_string_lossy()));
+            if first.exists() {
+                return first.to_string_lossy().into_owned();
+            }
+        }
+    }
+    path.to_string_lossy().into_owned()
+}
+
+// Solves or benchmarks the requested part(s) of already parsed input
+fn execute<S: Solution>(day: u32, input: &S::Input, part: Option<u32>, command: &Command) -> Report {
+    match *command {
+        Command::Run(_) => Report::Answers(solution::solve_parsed::<S>(input, part).into_iter()
+            .map(|(part, answer, elapsed)| Record { day, part, answer, elapsed })
+            .collect()),
+        Command::Bench(runs) => Report::Bench(Bench {
+            day,
+            runs,
+            parse: Default::default(),
+            parts: bench::bench_parsed::<S>(input, part, runs),
+        }),
+    }
+}
+
+// Parses the input with the given parser, timing it, then executes the command on it
+macro_rules! execute_with {
+    ($solution:ty, $parse:expr, $day:expr, $part:expr, $command:expr, $name:expr) => {{
+        let start = Instant::now();
+        let input = $parse.map_err(|e| e.in_file($name))?;
+        let parse_time = start.elapsed();
+        let mut report = execute::<$solution>($day, &input, $part, $command);
+        if let Report::Bench(ref mut bench) = report {
+            bench.parse = parse_time;
+        }
+        report
+    }};
+    ($solution:ty, $data:expr, $day:expr, $part:expr, $command:expr, $name:expr, default) => {
+        execute_with!($solution, <$solution>::parse($data), $day, $part, $command, $name)
+    };
+}
+
+// Common entry point for all the days
+fn execute_day(day: u32, part: Option<u32>, filename: &str, extra: &[String], command: &Command)
+    -> error::Result<Report> {
+    let name = solution::input_name(filename);
+
+    // Day 1 streams its input, so it can rank inventories too big to read in at once.
+    // When benchmarking, it reads the input first like the other days, so the parse time
+    // is only the ranking and not the file I/O.
+    if day == 1 {
+        let top = match extra.first() {
+            Some(val) => match val.parse::<usize>() {
+                Ok(top) if top > 0 => top,
+                _ => return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                    format!("Invalid number of elves {}", val)).into()),
+            },
+            None => day01::DEFAULT_TOP,
+        };
+        if let Command::Bench(_) = command {
+            let data = solution::read_input(filename)?;
+            return Ok(execute_with!(day01::Day01, day01::top_elves(&data, top), day, part, command, name));
+        }
+        let reader = solution::open_input(filename)?;
+        return Ok(execute_with!(day01::Day01, day01::read_top_elves(reader, top), day, part, command, name));
+    }
+
+    let data = solution::read_input(filename)?;
+    let report = match day {
+        2 => {
+            let rules = match extra.first() {
+                Some(rules_file) => day02::load_rules(rules_file)?,
+                None => day02::Rules::rock_paper_scissors(),
+            };
+            execute_with!(day02::Day02, day02::parse_with_rules(&data, rules), day, part, command, name)
+        },
+        3 => {
+            let group_size = match extra.first() {
+                Some(val) => match val.parse::<usize>() {
+                    Ok(group_size) if group_size > 0 => group_size,
+                    _ => return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                        format!("Invalid group size {}", val)).into()),
+                },
+                None => day03::GROUP_SIZE,
+            };
+            execute_with!(day03::Day03, day03::parse_with_group_size(&data, group_size), day, part, command, name)
+        },
+        4 => execute_with!(day04::Day04, &data, day, part, command, name, default),
+        5 => execute_with!(day05::Day05, &data, day, part, command, name, default),
+        6 => execute_with!(day06::Day06, &data, day, part, command, name, default),
+        7 => execute_with!(day07::Day07, &data, day, part, command, name, default),
+        8 => execute_with!(day08::Day08, &data, day, part, command, name, default),
+        9 => execute_with!(day09::Day09, &data, day, part, command, name, default),
+        10 => execute_with!(day10::Day10, &data, day, part, command, name, default),
+        11 => execute_with!(day11::Day11, &data, day, part, command, name, default),
+        12 => execute_with!(day12::Day12, &data, day, part, command, name, default),
+        13 => execute_with!(day13::Day13, &data, day, part, command, name, default),
+        14 => execute_with!(day14::Day14, &data, day, part, command, name, default),
+        15 => {
+            let test_val = match extra.first() {
+                Some(val) => Some(val.parse::<i32>().map_err(|e| {
+                    io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid test value {}: {}", val, e))
+                })?),
+                None => None,
+            };
+            execute_with!(day15::Day15, day15::parse_with_test_val(&data, test_val), day, part, command, name)
+        },
+        16 => execute_with!(day16::Day16, &data, day, part, command, name, default),
+        17 => execute_with!(day17::Day17, &data, day, part, command, name, default),
+        18 => execute_with!(day18::Day18, &data, day, part, command, name, default),
+        19 => execute_with!(day19::Day19, &data, day, part, command, name, default),
+        20 => execute_with!(day20::Day20, &data, day, part, command, name, default),
+        21 => execute_with!(day21::Day21, &data, day, part, command, name, default),
+        22 => execute_with!(day22::Day22, &data, day, part, command, name, default),
+        23 => execute_with!(day23::Day23, &data, day, part, command, name, default),
+        24 => execute_with!(day24::Day24, &data, day, part, command, name, default),
+        25 => execute_with!(day25::Day25, &data, day, part, command, name, default),
+        _ => unreachable!(),
+    };
+    Ok(report)
+}
+
+thread_local! {
+    // Where and why the last panic on this thread happened
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Runs a day's input, turning errors and panics into a reason for the failure
+fn run_day_isolated(day: u32, filename: &str) -> DayResult {
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        execute_day(day, None, filename, &[], &Command::Run(Format::Text))
+    }));
+    match outcome {
+        Ok(Ok(Report::Answers(records))) => Ok(records),
+        Ok(Ok(Report::Bench(_))) => unreachable!(),
+        // Parse errors already say which file they are in
+        Ok(Err(e @ error::Error::Parse(_))) => Err(e.to_string()),
+        Ok(Err(e)) => Err(format!("{}: {}", filename, e)),
+        Err(_) => {
+            let reason = LAST_PANIC.with(|last| last.borrow_mut().take());
+            let reason = reason.unwrap_or_else(|| "unknown reason".to_string());
+            Err(reason.replace('\n', " "))
+        },
+    }
+}
+
+// Runs the days' inputs concurrently, giving the results in the same order
+fn run_parallel(jobs: &[(u32, String)]) -> Vec<DayResult> {
+    // Keep the panic messages to report with the results instead of printing them as they happen,
+    // then put back whichever hook was there before
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(info.to_string()));
+    }));
+    let results = jobs.par_iter()
+        .map(|(day, filename)| run_day_isolated(*day, filename))
+        .collect();
+    panic::set_hook(prev_hook);
+    results
+}
+
+// Runs every day's input concurrently, and reports them all even if some fail.
+// Returns whether they all succeeded.
+fn run_all(input_name: &str, format: Format) -> bool {
+    let jobs: Vec<(u32, String)> = (1..=25)
+        .map(|day| (day, day_input(day, input_name)))
+        .collect();
+    let start = Instant::now();
+    let results: Vec<(u32, DayResult)> = jobs.iter().map(|(day, _)| *day)
+        .zip(run_parallel(&jobs))
+        .collect();
+    let elapsed = start.elapsed();
+
+    match format {
+        Format::Text => {
+            println!("{}", output::format_summary(&results));
+            println!("Total wall time: {:.3?}", elapsed);
+        },
+        _ => {
+            // Only the answers go to stdout, so flag the failures separately
+            let records: Vec<Record> = results.iter()
+                .filter_map(|(_, result)| result.as_ref().ok())
+                .flat_map(|records| records.iter().cloned())
+                .collect();
+            println!("{}", output::format_records(&records, format));
+            for (day, result) in &results {
+                if let Err(e) = result {
+                    eprintln!("Day {} FAILED: {}", day, e);
+                }
+            }
+        },
+    }
+    results.iter().all(|(_, result)| result.is_ok())
+}
+
+// Runs the days that have known answers and reports any that changed.
+// Returns whether they all matched.
+fn verify(days: &[u32], path: &str) -> error::Result<bool> {
+    let registry = Registry::load(path)?;
+    let jobs: Vec<(u32, String)> = days.iter()
+        .flat_map(|&day| registry.inputs(day).into_iter().map(move |input| (day, input.to_string())))
+        .collect();
+    if jobs.is_empty() {
+        eprintln!("No answers in {} for the requested days", path);
+        return Ok(false);
+    }
+
+    let mut num_checked = 0;
+    let mut num_matched = 0;
+    for ((day, input), result) in jobs.iter().zip(run_parallel(&jobs)) {
+        let expected: Vec<(u32, &str)> = [1, 2].iter()
+            .filter_map(|&part| registry.get(*day, part, input).map(|answer| (part, answer)))
+            .collect();
+        num_checked += expected.len();
+        let records = match result {
+            Ok(records) => records,
+            Err(e) => {
+                println!("FAILED   day {} {}: {}", day, input, e);
+                continue;
+            },
+        };
+        for (part, expected) in expected {
+            let actual = records.iter().find(|r| r.part == part).map_or(Answer::None, |r| r.answer.clone());
+            let actual = registry::encode(&actual);
+            if actual == expected {
+                num_matched += 1;
+            } else {
+                println!("MISMATCH day {} part {} {}: expected {}, got {}", day, part, input, expected, actual);
+            }
+        }
+    }
+    println!("{} of {} answers match", num_matched, num_checked);
+    Ok(num_matched == num_checked)
+}
+
+// Runs the days that have no known answers for the input yet, and adds their answers.
+// Returns whether they all ran successfully.
+fn record(days: &[u32], input_name: &str, path: &str, force: bool) -> error::Result<bool> {
+    let mut registry = Registry::load(path)?;
+    let jobs: Vec<(u32, String)> = days.iter()
+        .map(|&day| (day, day_input(day, input_name)))
+        .filter(|(day, input)| force || !registry.inputs(*day).contains(input.as_str()))
+        .collect();
+    if jobs.is_empty() {
+        eprintln!("All the requested days already have answers in {} (use --force to record them again)", path);
+        return Ok(true);
+    }
+
+    let mut all_ok = true;
+    for ((day, input), result) in jobs.iter().zip(run_parallel(&jobs)) {
+        match result {
+            Ok(records) => {
+                // A part without an answer has nothing to check
+                for r in records.iter().filter(|r| r.answer != Answer::None) {
+                    registry.insert(*day, r.part, input, &r.answer);
+                    println!("Recorded day {} part {} {}: {}", day, r.part, input, registry::encode(&r.answer));
+                }
+            },
+            Err(e) => {
+                println!("FAILED   day {} {}: {}", day, input, e);
+                all_ok = false;
+            },
+        }
+    }
+    registry.save(path)?;
+    Ok(all_ok)
+}
+
+// Compares the reference and candidate implementations of the days on generated inputs.
+// Returns whether they all agreed.
+fn diff(days: &[u32], num_seeds: u64, max_size: usize) -> bool {
+    let pairs = differential::pairs(days);
+    if pairs.is_empty() {
+        eprintln!("No implementations to compare for the requested days");
+        return false;
+    }
+    let mut all_ok = true;
+    for pair in pairs {
+        match differential::compare(pair, 0..num_seeds, 1..max_size + 1) {
+            Ok(num_inputs) => println!("OK       day {} {}: {} inputs", pair.day, pair.name, num_inputs),
+            Err(divergence) => {
+                println!("DIVERGED day {} {}: {}", pair.day, pair.name, divergence);
+                all_ok = false;
+            },
+        }
+    }
+    all_ok
+}
+
 fn main() {
-    println!("Welcome to Advent of Code 2022!");
-}
\ No newline at end of file
+    let mut args: Vec<String> = env::args().collect();
+
+    // Pull out the options, wherever they are
+    let quiet = take_flag(&mut args, "-q") | take_flag(&mut args, "--quiet");
+    let mut verbosity = 0;
+    while take_flag(&mut args, "-v") || take_flag(&mut args, "--verbose") {
+        verbosity += 1;
+    }
+    while take_flag(&mut args, "-vv") {
+        verbosity += 2;
+    }
+    if quiet && verbosity > 0 {
+        usage_error("-q cannot be used with -v");
+    }
+    log::set_level(log::from_flags(quiet, verbosity));
+    let format = match take_option(&mut args, "--format").map(|val| val.parse::<Format>()) {
+        Some(Ok(format)) => format,
+        Some(Err(e)) => usage_error(&e),
+        None => Format::Text,
+    };
+    let runs = match take_option(&mut args, "--runs").map(|val| val.parse::<usize>()) {
+        Some(Ok(runs)) if runs > 0 => runs,
+        Some(_) => usage_error("The number of runs must be a positive integer"),
+        None => DEFAULT_RUNS,
+    };
+
+    let animate_fps = take_animate(&mut args);
+    let frame_skip = match take_option(&mut args, "--frame-skip").map(|val| val.parse::<usize>()) {
+        Some(Ok(skip)) if skip > 0 => Some(skip),
+        Some(_) => usage_error("The frame skip must be a positive integer"),
+        None => None,
+    };
+    if animate_fps.is_some() || frame_skip.is_some() {
+        if args.get(1).map(|arg| arg.as_str()) != Some("run") {
+            usage_error("--animate and --frame-skip only work with the run command");
+        }
+        animate::enable(animate::Settings {
+            fps: animate_fps.unwrap_or(animate::DEFAULT_FPS),
+            skip: frame_skip.unwrap_or(1),
+        });
+    }
+
+    if let Some(dir) = take_option(&mut args, "--dump-frames") {
+        if args.get(1).map(|arg| arg.as_str()) != Some("run") {
+            usage_error("--dump-frames only works with the run command");
+        }
+        if let Err(e) = fs::create_dir_all(&dir) {
+            eprintln!("Could not create {}: {}", dir, e);
+            process::exit(1);
+        }
+        image::set_dump_dir(Some(PathBuf::from(dir)));
+    }
+
+    let answers_path = take_option(&mut args, "--answers").unwrap_or_else(|| registry::DEFAULT_PATH.to_string());
+    let record_input = take_option(&mut args, "--input").unwrap_or_else(|| "puzzle_input.txt".to_string());
+    let force = take_flag(&mut args, "--force");
+    let search = take_flag(&mut args, "--search");
+    let top = match take_option(&mut args, "--top").map(|val| val.parse::<usize>()) {
+        Some(Ok(top)) if top > 0 => Some(top),
+        Some(_) => usage_error("The number of meanings to show must be a positive integer"),
+        None => None,
+    };
+    let audit = take_flag(&mut args, "--audit");
+    let group_size = take_option(&mut args, "--group-size");
+    let seed = match take_option(&mut args, "--seed").map(|val| val.parse::<u64>()) {
+        Some(Ok(seed)) => Some(seed),
+        Some(Err(_)) => usage_error("The seed must be a non-negative integer"),
+        None => None,
+    };
+    let size = match take_option(&mut args, "--size").map(|val| val.parse::<usize>()) {
+        Some(Ok(size)) if size > 0 => Some(size),
+        Some(_) => usage_error("The size must be a positive integer"),
+        None => None,
+    };
+    let seeds = match take_option(&mut args, "--seeds").map(|val| val.parse::<u64>()) {
+        Some(Ok(seeds)) if seeds > 0 => Some(seeds),
+        Some(_) => usage_error("The number of seeds must be a positive integer"),
+        None => None,
+    };
+    let command = args.get(1).map(|arg| arg.as_str());
+    if seed.is_some() && command != Some("gen") {
+        usage_error("--seed only works with the gen command");
+    }
+    if seeds.is_some() && command != Some("diff") {
+        usage_error("--seeds only works with the diff command");
+    }
+    if group_size.is_some() && command != Some("run") && command != Some("bench") {
+        usage_error("--group-size only works with the run and bench commands");
+    }
+    if size.is_some() && command != Some("gen") && command != Some("diff") {
+        usage_error("--size only works with the gen and diff commands");
+    }
+
+    if command == Some("diff") {
+        let days: Vec<u32> = args[2..].iter()
+            .map(|arg| parse_day(arg).unwrap_or_else(|| usage_error(&format!("Invalid day: {}", arg))))
+            .collect();
+        let size = size.unwrap_or(generate::DEFAULT_SIZE);
+        if !diff(&days, seeds.unwrap_or(differential::DEFAULT_SEEDS), size) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if command == Some("gen") {
+        if args.len() != 3 {
+            usage_error("gen takes exactly one day");
+        }
+        let day = parse_day(&args[2]).unwrap_or_else(|| usage_error(&format!("Invalid day: {}", args[2])));
+        print!("{}", generate::generate(day, seed.unwrap_or(generate::DEFAULT_SEED),
+                                        size.unwrap_or(generate::DEFAULT_SIZE)));
+        return;
+    }
+
+    if let Some(command @ "verify") | Some(command @ "record") = args.get(1).map(|arg| arg.as_str()) {
+        let mut days: Vec<u32> = args[2..].iter()
+            .map(|arg| parse_day(arg).unwrap_or_else(|| usage_error(&format!("Invalid day: {}", arg))))
+            .collect();
+        if days.is_empty() {
+            days = (1..=25).collect();
+        }
+        let outcome = if command == "verify" {
+            verify(&days, &answers_path)
+        } else {
+            record(&days, &record_input, &answers_path, force)
+        };
+        match outcome {
+            Ok(true) => {},
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("Error with answers in {}: {}", answers_path, e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if args.get(1).map(|arg| arg.as_str()) == Some("all") {
+        if args.len() > 3 {
+            usage_error("Too many arguments for all");
+        }
+        let input_name = args.get(2).map_or("puzzle_input.txt", |arg| arg.as_str());
+        if !run_all(input_name, format) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if args.len() < 3 {
+        print_usage();
+        process::exit(2);
+    }
+    let command = match args[1].as_str() {
+        "run" => Command::Run(format),
+        "bench" => Command::Bench(runs),
+        other => usage_error(&format!("Unknown command: {}", other)),
+    };
+
+    let day = match parse_day(&args[2]) {
+        Some(day) => day,
+        None => usage_error(&format!("Invalid day: {}", args[2])),
+    };
+
+    // The remaining arguments are [part] [input] [extra], where the part may be omitted.
+    let mut rest = &args[3..];
+    let part = rest.first().and_then(|arg| parse_part(arg));
+    if part.is_some() {
+        rest = &rest[1..];
+    }
+    let filename = match rest.first() {
+        Some(filename) => filename.clone(),
+        None => default_input(day),
+    };
+    let mut extra = if rest.is_empty() { rest.to_vec() } else { rest[1..].to_vec() };
+
+    // --group-size is another way to give Day 3 its extra argument
+    if let Some(group_size) = group_size {
+        if day != 3 || !extra.is_empty() {
+            usage_error("--group-size only works with day 3, in place of its extra argument");
+        }
+        extra.push(group_size);
+    }
+
+    if top.is_some() && !search {
+        usage_error("--top only works with --search");
+    }
+    if search {
+        if day != 2 || part.is_some() || !matches!(command, Command::Run(Format::Text)) {
+            usage_error("--search only works when running both parts of day 2 as text");
+        }
+        if let Err(e) = day02::search(&filename, extra.first().map(|arg| arg.as_str()),
+                                    top.unwrap_or(day02::DEFAULT_TOP)) {
+            eprintln!("Error searching day 2 with input {}: {}", filename, e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if audit {
+        if day != 4 || part.is_some() || !extra.is_empty() || !matches!(command, Command::Run(Format::Text)) {
+            usage_error("--audit only works when running both parts of day 4 as text");
+        }
+        if let Err(e) = day04::audit(&filename) {
+            eprintln!("Error auditing day 4 with input {}: {}", filename, e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    match execute_day(day, part, &filename, &extra, &command) {
+        Ok(Report::Answers(records)) => {
+            if let Command::Run(format) = command {
+                println!("{}", output::format_records(&records, format));
+            }
+        },
+        Ok(Report::Bench(bench)) => println!("{}", bench),
+        Err(e) => {
+            eprintln!("Error running day {} with input {}: {}", day, filename, e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// Output formats for the answers
+//
+// Besides the plain text meant for people, the answers can be printed as JSON or CSV
+// records of {day, part, answer, elapsed} for other tools to consume.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use solution::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format {}, expected text, json, or csv", s)),
+        }
+    }
+}
+
+// The answer to one part of one day, and how long it took to compute
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,elapsed";
+
+fn json_string(val: &str) -> String {
+    let mut s = String::from("\"");
+    for c in val.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+fn csv_field(val: &str) -> String {
+    if val.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", val.replace('"', "\"\""))
+    } else {
+        val.to_string()
+    }
+}
+
+impl Record {
+    // Elapsed time is given in seconds
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Answer::Int(val) => val.to_string(),
+            Answer::Str(val) | Answer::Art(val) => json_string(val),
+            Answer::None => "null".to_string(),
+        };
+        format!("{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {:.6}}}",
+            self.day, self.part, answer, self.elapsed.as_secs_f64())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let answer = match &self.answer {
+            Answer::Int(val) => val.to_string(),
+            Answer::Str(val) | Answer::Art(val) => csv_field(val),
+            Answer::None => String::new(),
+        };
+        format!("{},{},{},{:.6}", self.day, self.part, answer, self.elapsed.as_secs_f64())
+    }
+}
+
+pub fn format_records(records: &[Record], format: Format) -> String {
+    let lines: Vec<String> = match format {
+        Format::Text => records.iter()
+            .map(|r| format!("Part {}: {}", r.part, r.answer))
+            .collect(),
+        Format::Json => {
+            let items: Vec<String> = records.iter().map(|r| format!("  {}", r.to_json())).collect();
+            if items.is_empty() {
+                vec!["[]".to_string()]
+            } else {
+                vec!["[".to_string(), items.join(",\n"), "]".to_string()]
+            }
+        },
+        Format::Csv => {
+            let mut lines = vec![CSV_HEADER.to_string()];
+            lines.extend(records.iter().map(|r| r.to_csv()));
+            lines
+        },
+    };
+    lines.join("\n")
+}
+
+// The outcome of running one day: its answers, or why it failed
+pub type DayResult = Result<Vec<Record>, String>;
+
+// Formats the results of running several days as a table for people to read.
+// Failed days are flagged with the reason instead of their answers.
+pub fn format_summary(results: &[(u32, DayResult)]) -> String {
+    let mut lines = vec![format!("{:>3}  {:>4}  {:<20}  {:>12}", "Day", "Part", "Answer", "Elapsed")];
+    for (day, result) in results {
+        match result {
+            Ok(records) => {
+                for r in records {
+                    let answer = match &r.answer {
+                        Answer::Art(_) => "(see below)".to_string(),
+                        answer => answer.to_string(),
+                    };
+                    lines.push(format!("{:>3}  {:>4}  {:<20}  {:>12.3?}", day, r.part, answer, r.elapsed));
+                    // Multi-line answers go under their row, indented to the answer column
+                    if let Answer::Art(art) = &r.answer {
+                        lines.extend(art.lines().map(|line| format!("{:11}{}", "", line)));
+                    }
+                }
+            },
+            Err(e) => lines.push(format!("{:>3}  {:>4}  FAILED: {}", day, "-", e)),
+        }
+    }
+    let num_failed = results.iter().filter(|(_, result)| result.is_err()).count();
+    lines.push(String::new());
+    lines.push(format!("{} of {} days succeeded", results.len() - num_failed, results.len()));
+    lines.join("\n")
+}
@@ -0,0 +1,120 @@
+// Registry of known answers
+//
+// Keeps the answer to each day and part for a given input file, so a refactor
+// can be checked against them. Each line of the file is "day part input answer",
+// where multi-line answers have their newlines written as \n, and spaces in the
+// input file are written as "\ " so the answer starts after the first plain space.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io;
+
+use error::{self, ParseError};
+use solution::Answer;
+
+pub const DEFAULT_PATH: &str = "answers.txt";
+
+const HEADER: &str = "\
+# Known answers, checked by `aoc verify` and added to by `aoc record`
+# day part input answer";
+
+// Identifies an answer by day, part, and input file
+pub type Key = (u32, u32, String);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Registry {
+    pub answers: BTreeMap<Key, String>,
+}
+
+// Writes an answer on a single line
+pub fn encode(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(val) => val.to_string(),
+        Answer::Str(val) | Answer::Art(val) => val.replace('\\', "\\\\").replace('\n', "\\n"),
+        Answer::None => "-".to_string(),
+    }
+}
+
+// Writes an input file as a single token
+pub fn encode_input(input: &str) -> String {
+    input.replace('\\', "\\\\").replace(' ', "\\ ")
+}
+
+// Reads an input file token from the start of the text, returning it and the rest
+// of the text after the space that ends it
+fn decode_input(text: &str) -> (String, Option<&str>) {
+    let mut input = String::new();
+    let mut chars = text.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\\' => input.extend(chars.next().map(|(_, c)| c)),
+            ' ' => return (input, Some(&text[idx + 1..])),
+            _ => input.push(c),
+        }
+    }
+    (input, None)
+}
+
+impl Registry {
+    pub fn parse(data: &str) -> Result<Self, ParseError> {
+        let mut registry = Registry::default();
+        for (i, line) in data.lines().enumerate() {
+            let line_num = i + 1;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut tokens = line.splitn(3, ' ');
+            let day = error::parse_token::<u32>(line_num, line, tokens.next().unwrap(), "a day")?;
+            let part_token = error::expect(tokens.next(), line_num, line.len() + 1, "a part")?;
+            let part = error::parse_token::<u32>(line_num, line, part_token, "a part")?;
+            let rest = error::expect(tokens.next(), line_num, line.len() + 1, "an input file")?;
+            let (input, answer) = decode_input(rest);
+            if input.is_empty() {
+                return Err(ParseError::new(line_num, error::column_of(line, rest), "an input file"));
+            }
+            let answer = error::expect(answer, line_num, line.len() + 1, "an answer")?;
+            registry.answers.insert((day, part, input), answer.to_string());
+        }
+        Ok(registry)
+    }
+
+    // Loads the registry from a file, which is empty if the file does not exist yet
+    pub fn load(path: &str) -> error::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(data) => Ok(Registry::parse(&data).map_err(|e| e.in_file(path))?),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(Registry::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u32, part: u32, input: &str) -> Option<&str> {
+        self.answers.get(&(day, part, input.to_string())).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, input: &str, answer: &Answer) {
+        self.answers.insert((day, part, input.to_string()), encode(answer));
+    }
+
+    // The input files that have answers for a day
+    pub fn inputs(&self, day: u32) -> BTreeSet<&str> {
+        self.answers.keys()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, _, input)| input.as_str())
+            .collect()
+    }
+}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for ((day, part, input), answer) in &self.answers {
+            writeln!(f, "{} {} {} {}", day, part, encode_input(input), answer)?;
+        }
+        Ok(())
+    }
+}
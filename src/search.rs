@@ -0,0 +1,117 @@
+// Graph searches shared by the path finding puzzles
+//
+// Each search starts from a single node and is given a function that lists the
+// neighbours of a node, and a function that says whether a node is the goal.
+// The result is the cost to reach the first goal found and the path taken,
+// from the start to the goal inclusive, or None if no goal can be reached.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+// Follows the parent links back from a node to the start
+fn reconstruct<N: Clone, C>(nodes: &[(N, Option<usize>)], mut idx: usize, cost: C) -> Path<N, C> {
+    let mut path = vec![nodes[idx].0.clone()];
+    while let Some(parent) = nodes[idx].1 {
+        path.push(nodes[parent].0.clone());
+        idx = parent;
+    }
+    path.reverse();
+    Path { cost, nodes: path }
+}
+
+// Breadth-first search, where every step costs 1
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbours: FN, mut is_goal: FG) -> Option<Path<N, usize>>
+    where N: Clone + Eq + Hash,
+          FN: FnMut(&N) -> IN,
+          IN: IntoIterator<Item = N>,
+          FG: FnMut(&N) -> bool {
+    // Nodes are kept in the order they are found, with the index of the node they were reached from
+    let mut nodes = vec![(start.clone(), None)];
+    let mut seen = HashSet::new();
+    seen.insert(start);
+    let mut queue = VecDeque::new();
+    queue.push_back((0, 0));
+
+    while let Some((idx, steps)) = queue.pop_front() {
+        if is_goal(&nodes[idx].0) {
+            return Some(reconstruct(&nodes, idx, steps));
+        }
+        for next in neighbours(&nodes[idx].0) {
+            if seen.insert(next.clone()) {
+                nodes.push((next, Some(idx)));
+                queue.push_back((nodes.len() - 1, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+// Dijkstra's algorithm, where the neighbours come with the cost of the step to them
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, neighbours: FN, is_goal: FG) -> Option<Path<N, C>>
+    where N: Clone + Eq + Hash,
+          C: Copy + Ord + Zero,
+          FN: FnMut(&N) -> IN,
+          IN: IntoIterator<Item = (N, C)>,
+          FG: FnMut(&N) -> bool {
+    astar(start, neighbours, |_| C::zero(), is_goal)
+}
+
+// A* search, guided by a heuristic that must never overestimate the cost to a goal
+pub fn astar<N, C, FN, IN, FH, FG>(start: N, mut neighbours: FN, mut heuristic: FH, mut is_goal: FG)
+    -> Option<Path<N, C>>
+    where N: Clone + Eq + Hash,
+          C: Copy + Ord + Zero + Add<Output = C>,
+          FN: FnMut(&N) -> IN,
+          IN: IntoIterator<Item = (N, C)>,
+          FH: FnMut(&N) -> C,
+          FG: FnMut(&N) -> bool {
+    // The best known cost to each node found so far, by its index in nodes
+    let mut nodes = vec![(start.clone(), None)];
+    let mut costs = vec![C::zero()];
+    let mut index = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&start), C::zero(), 0)));
+    index.insert(start, 0);
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        // Skip entries left behind when a cheaper way to the node was found
+        if cost > costs[idx] {
+            continue;
+        }
+        if is_goal(&nodes[idx].0) {
+            return Some(reconstruct(&nodes, idx, cost));
+        }
+        for (next, step_cost) in neighbours(&nodes[idx].0) {
+            let next_cost = cost + step_cost;
+            let next_idx = match index.entry(next.clone()) {
+                Entry::Occupied(entry) => {
+                    let next_idx = *entry.get();
+                    if costs[next_idx] <= next_cost {
+                        continue;
+                    }
+                    costs[next_idx] = next_cost;
+                    nodes[next_idx].1 = Some(idx);
+                    next_idx
+                },
+                Entry::Vacant(entry) => {
+                    nodes.push((next.clone(), Some(idx)));
+                    costs.push(next_cost);
+                    *entry.insert(nodes.len() - 1)
+                },
+            };
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, next_idx)));
+        }
+    }
+    None
+}
@@ -0,0 +1,138 @@
+// Common interface for all the days' solutions
+//
+// Each day parses its puzzle input once, then computes both parts from the
+// parsed input and returns the answers as values instead of printing them.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
+use std::time::{Duration, Instant};
+
+use error::{self, ParseError};
+
+// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    // Multi-line output that has to be read by eye, like the Day 10 CRT
+    Art(String),
+    // The part has no answer (e.g. the second part of the last day)
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(val) => write!(f, "{}", val),
+            Answer::Str(val) => write!(f, "{}", val),
+            Answer::Art(val) => write!(f, "\n{}", val),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(val: $t) -> Self {
+                    Answer::Int(val as i64)
+                }
+            }
+        )*
+    };
+}
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(val: String) -> Self {
+        Answer::Str(val)
+    }
+}
+
+impl<'a> From<&'a str> for Answer {
+    fn from(val: &'a str) -> Self {
+        Answer::Str(val.to_string())
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+// Passing this as the filename reads the puzzle input from stdin
+pub const STDIN: &str = "-";
+
+// Reads the puzzle input from a file, or from stdin
+pub fn read_input(filename: &str) -> io::Result<String> {
+    if filename == STDIN {
+        let mut data = String::new();
+        io::stdin().read_to_string(&mut data)?;
+        Ok(data)
+    } else {
+        fs::read_to_string(filename)
+    }
+}
+
+// Opens the puzzle input from a file, or from stdin, to be read a line at a time
+pub fn open_input(filename: &str) -> io::Result<Box<dyn BufRead>> {
+    if filename == STDIN {
+        Ok(Box::new(BufReader::new(io::stdin())))
+    } else {
+        Ok(Box::new(BufReader::new(fs::File::open(filename)?)))
+    }
+}
+
+// Name of the input to use in error messages
+pub fn input_name(filename: &str) -> &str {
+    if filename == STDIN { "<stdin>" } else { filename }
+}
+
+// When no input file is given, read from stdin if something is piped in,
+// otherwise fall back to the given default (usually the test input).
+pub fn default_input(default: &str) -> &str {
+    if io::stdin().is_terminal() { default } else { STDIN }
+}
+
+// Solves the requested part(s) of already parsed input, timing each part
+pub fn solve_parsed<S: Solution>(input: &S::Input, part: Option<u32>) -> Vec<(u32, Answer, Duration)> {
+    let mut answers = Vec::new();
+    if matches!(part, None | Some(1)) {
+        let start = Instant::now();
+        let answer = S::part1(input);
+        answers.push((1, answer, start.elapsed()));
+    }
+    if matches!(part, None | Some(2)) {
+        let start = Instant::now();
+        let answer = S::part2(input);
+        answers.push((2, answer, start.elapsed()));
+    }
+    answers
+}
+
+// Parses the data and solves the requested part(s), or both if no part is given
+pub fn solve<S: Solution>(data: &str, part: Option<u32>) -> Result<Vec<(u32, Answer)>, ParseError> {
+    let input = S::parse(data)?;
+    Ok(solve_parsed::<S>(&input, part).into_iter()
+        .map(|(part, answer, _)| (part, answer))
+        .collect())
+}
+
+pub fn print_answers(answers: &[(u32, Answer, Duration)]) {
+    for (part, answer, _) in answers {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+// Reads the file, solves the requested part(s), and prints the answers
+pub fn run<S: Solution>(filename: &str, part: Option<u32>) -> error::Result<()> {
+    let data = read_input(filename)?;
+    let input = S::parse(&data).map_err(|e| e.in_file(input_name(filename)))?;
+    print_answers(&solve_parsed::<S>(&input, part));
+    Ok(())
+}
@@ -0,0 +1,11 @@
+// Tests for the terminal animation of the simulation days
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::animate;
+
+#[test]
+fn frames_not_rendered_when_disabled() {
+    assert!(!animate::is_enabled());
+    animate::frame(|| panic!("rendered a frame while animation was off"));
+    animate::last_frame(|| panic!("rendered a frame while animation was off"));
+}
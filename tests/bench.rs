@@ -0,0 +1,36 @@
+// Tests for the benchmarking helpers
+
+use std::time::Duration;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::bench::{bench_parsed, Stats};
+use advent_of_code_2022::day01::Day01;
+use advent_of_code_2022::solution::{Answer, Solution};
+
+fn ms(vals: &[u64]) -> Vec<Duration> {
+    vals.iter().map(|&val| Duration::from_millis(val)).collect()
+}
+
+#[test]
+fn stats_odd_samples() {
+    let stats = Stats::from_samples(&ms(&[5, 1, 9, 3, 7]));
+    assert_eq!(stats, Stats { min: Duration::from_millis(1), median: Duration::from_millis(5), max: Duration::from_millis(9) });
+}
+
+#[test]
+fn stats_even_samples() {
+    let stats = Stats::from_samples(&ms(&[4, 1, 2, 8]));
+    assert_eq!(stats, Stats { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(8) });
+}
+
+#[test]
+fn bench_runs_requested_parts() {
+    let input = Day01::parse("1000\n2000\n\n4000\n\n500\n").unwrap();
+    let parts = bench_parsed::<Day01>(&input, None, 3);
+    let answers: Vec<(u32, Answer)> = parts.into_iter().map(|p| (p.part, p.answer)).collect();
+    assert_eq!(answers, vec![(1, Answer::Int(4000)), (2, Answer::Int(7500))]);
+
+    let parts = bench_parsed::<Day01>(&input, Some(2), 1);
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].part, 2);
+}
@@ -0,0 +1,46 @@
+// Tests for the cycle detection used to extrapolate long simulations
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::cycle::{Cycle, CycleDetector};
+
+// Steps through x -> (x * x + 1) mod 10 from 3, adding the state to a running total,
+// which goes 3, 0, 1, 2, 5, 6, 7, 0, ... so cycles with length 6 from step 1
+fn run(steps: usize) -> (CycleDetector<u32>, Vec<i64>) {
+    let mut detector = CycleDetector::new();
+    let mut totals = Vec::new();
+    let (mut x, mut total) = (3, 0);
+    for _ in 0..steps {
+        total += x as i64;
+        totals.push(total);
+        detector.push(x, total);
+        x = (x * x + 1) % 10;
+    }
+    (detector, totals)
+}
+
+#[test]
+fn finds_cycle() {
+    let (detector, _) = run(7);
+    assert_eq!(detector.cycle(), None);
+    let (detector, _) = run(8);
+    assert_eq!(detector.cycle(), Some(Cycle { start: 1, length: 6 }));
+    assert_eq!(detector.len(), 8);
+}
+
+#[test]
+fn extrapolate_matches_simulation() {
+    let (detector, _) = run(8);
+    let (_, totals) = run(100);
+    for (step, &total) in totals.iter().enumerate() {
+        assert_eq!(detector.extrapolate(step), Some(total), "step {}", step);
+    }
+    // Each cycle adds 0 + 1 + 2 + 5 + 6 + 7
+    assert_eq!(detector.extrapolate(1 + 6 * 1000), Some(3 + 21 * 1000));
+}
+
+#[test]
+fn extrapolate_needs_cycle() {
+    let (detector, totals) = run(5);
+    assert_eq!(detector.extrapolate(4), Some(totals[4]));
+    assert_eq!(detector.extrapolate(5), None);
+}
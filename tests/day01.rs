@@ -0,0 +1,45 @@
+// Tests for ranking the elves carrying the most calories
+
+use std::io::Cursor;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::day01::{self, Elf};
+
+fn elf(index: usize, calories: i64) -> Elf {
+    Elf { index, calories }
+}
+
+#[test]
+fn ranks_top_elves() {
+    let top = day01::top_elves("1000\n2000\n\n4000\n\n5000\n6000\n\n7000\n\n500\n", 3).unwrap();
+    assert_eq!(top, vec![elf(3, 11000), elf(4, 7000), elf(2, 4000)]);
+}
+
+#[test]
+fn keeps_last_elf_without_blank_line() {
+    assert_eq!(day01::top_elves("100\n\n200\n300", 1).unwrap(), vec![elf(2, 500)]);
+    assert_eq!(day01::top_elves("100\n\n200\n300\n\n\n", 1).unwrap(), vec![elf(2, 500)]);
+}
+
+#[test]
+fn ties_go_to_first_elf() {
+    let top = day01::top_elves("5\n\n7\n\n5\n\n5\n", 2).unwrap();
+    assert_eq!(top, vec![elf(2, 7), elf(1, 5)]);
+}
+
+#[test]
+fn fewer_elves_than_requested() {
+    assert_eq!(day01::top_elves("1\n\n2\n", 5).unwrap(), vec![elf(2, 2), elf(1, 1)]);
+    assert_eq!(day01::top_elves("", 3).unwrap(), vec![]);
+}
+
+#[test]
+fn streams_from_reader() {
+    // Many elves, carrying 0 to 9999 calories
+    let inventory: String = (0..10000).map(|i| format!("{}\n{}\n\n", i / 2, i - i / 2)).collect();
+    let top = day01::read_top_elves(Cursor::new(inventory), 3).unwrap();
+    assert_eq!(top, vec![elf(10000, 9999), elf(9999, 9998), elf(9998, 9997)]);
+
+    let e = day01::read_top_elves(Cursor::new("1\n\n2x\n"), 3).unwrap_err();
+    assert_eq!(e.in_file("inventory.txt").to_string(), "Parse error: inventory.txt:3:1: expected a calorie count");
+}
@@ -0,0 +1,143 @@
+// Tests for scoring hand games from a table of rules
+
+use std::fs;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::day02::{self, Day02, Interpretation, Outcome, Rules, Weapon};
+use advent_of_code_2022::solution::{solve, Answer, Solution};
+
+fn weapon(name: &str, opponent_code: char, my_code: char, score: i32) -> Weapon {
+    Weapon { name: name.to_string(), opponent_code, my_code, score }
+}
+
+fn rules_file(name: &str) -> Rules {
+    Rules::parse(&fs::read_to_string(format!("data/day02/{}", name)).unwrap()).unwrap()
+}
+
+#[test]
+fn rock_paper_scissors() {
+    let rules = Rules::rock_paper_scissors();
+    assert_eq!(rules.outcome(1, 0), Outcome::Win);
+    assert_eq!(rules.outcome(0, 1), Outcome::Lose);
+    assert_eq!(rules.outcome(2, 2), Outcome::Draw);
+    assert_eq!(rules.score_round(('A', 'Y'), &rules.moves_as_listed()), Some(8));
+    assert_eq!(rules.score_round(('A', 'Y'), &Interpretation::Outcomes), Some(4));
+
+    // The same game from a table
+    let table = "weapon rock A X 1\nweapon paper B Y 2\nweapon scissors C Z 3\n\
+                 beats rock scissors\nbeats paper rock\nbeats scissors paper\n\
+                 outcome lose X 0\noutcome draw Y 3\noutcome win Z 6\n";
+    assert_eq!(Rules::parse(table).unwrap(), rules);
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let rules = rules_file("rpsls_rules.txt");
+    let data = fs::read_to_string("data/day02/rpsls_test_input.txt").unwrap();
+    let input = day02::parse_with_rules(&data, rules.clone()).unwrap();
+    assert_eq!((Day02::part1(&input), Day02::part2(&input)), (Answer::Int(44), Answer::Int(33)));
+
+    // Listed in the right order, each weapon beats the two before it
+    let cyclic = Rules::cyclic(
+        vec![weapon("rock", 'A', 'V', 1), weapon("spock", 'D', 'Y', 4), weapon("paper", 'B', 'W', 2),
+             weapon("lizard", 'E', 'Z', 5), weapon("scissors", 'C', 'X', 3)],
+        rules.outcomes).unwrap();
+    for mine in 0..5 {
+        for theirs in 0..5 {
+            let find = |name: &str| rules.weapons.iter().position(|weapon| weapon.name == name).unwrap();
+            let (mine_name, theirs_name) = (&cyclic.weapons[mine].name, &cyclic.weapons[theirs].name);
+            assert_eq!(cyclic.outcome(mine, theirs), rules.outcome(find(mine_name), find(theirs_name)),
+                "{} vs. {}", mine_name, theirs_name);
+        }
+    }
+}
+
+#[test]
+fn picks_best_weapon_for_outcome() {
+    // Against rock, water, air and paper all win in the 7 weapon game, and paper scores the most
+    let rules = rules_file("rps7_rules.txt");
+    let paper = rules.weapons.iter().position(|weapon| weapon.name == "paper").unwrap();
+    assert_eq!(rules.choose(0, Outcome::Win), paper);
+    assert_eq!(rules.choose(0, Outcome::Draw), 0);
+}
+
+#[test]
+fn ties_go_to_weapon_listed_first() {
+    let outcomes = [('X', 0), ('Y', 3), ('Z', 6)];
+    let rules = Rules::cyclic(
+        vec![weapon("rock", 'A', 'V', 1), weapon("spock", 'D', 'Y', 2), weapon("paper", 'B', 'W', 2),
+             weapon("lizard", 'E', 'Z', 3), weapon("scissors", 'C', 'X', 3)],
+        outcomes).unwrap();
+    // Spock and paper both beat rock for 2, as do lizard and scissors against paper for 3
+    assert_eq!(rules.choose(0, Outcome::Win), 1);
+    assert_eq!(rules.choose(2, Outcome::Win), 3);
+    // Lizard and scissors both lose to rock for 3
+    assert_eq!(rules.choose(0, Outcome::Lose), 3);
+}
+
+#[test]
+fn codes_without_meaning() {
+    // V is a move in this game, but not an outcome
+    let input = day02::parse_with_rules("A V\n", rules_file("rpsls_rules.txt")).unwrap();
+    assert_eq!(Day02::part1(&input), Answer::Int(4));
+    assert_eq!(Day02::part2(&input), Answer::None);
+
+    // The default game has no V
+    assert_eq!(solve::<Day02>("A V\n", None).unwrap_err().column, 3);
+}
+
+#[test]
+fn bad_rules() {
+    let outcomes = "outcome lose X 0\noutcome draw Y 3\noutcome win Z 6\n";
+    let check_error = |table: &str, line: usize, column: usize| {
+        let e = Rules::parse(table).unwrap_err();
+        assert_eq!((e.line, e.column), (line, column), "{}", e);
+    };
+    // Nothing says who wins between paper and scissors
+    check_error(&format!("weapon rock A X 1\nweapon paper B Y 2\nweapon scissors C Z 3\n\
+                          beats rock scissors\nbeats paper rock\n{}", outcomes), 9, 1);
+    check_error(&format!("weapon rock A X 1\nweapon paper B Y 2\nbeats paper stone\n{}", outcomes), 3, 13);
+    check_error(&format!("weapon rock A X 1\nweapon paper B Y 2\ncyclic\n{}", outcomes), 7, 1);
+    check_error(&format!("weapon rock A X 1\nweapon paper A Y 2\n{}", outcomes), 2, 8);
+    check_error("weapon rock A X 1\nweapon paper B Y 2\nbeats paper rock\noutcome win Z 6\n", 5, 1);
+    check_error("throw rock\n", 1, 1);
+
+    // Cyclic games need an odd number of weapons
+    let e = Rules::cyclic(vec![weapon("rock", 'A', 'X', 1), weapon("paper", 'B', 'Y', 2)],
+                          [('X', 0), ('Y', 3), ('Z', 6)]).unwrap_err();
+    assert_eq!(e.expected, "an odd number of weapons for a cyclic game");
+}
+
+#[test]
+fn ranks_interpretations() {
+    let data = fs::read_to_string("data/day02/test_input.txt").unwrap();
+    let input = Day02::parse(&data).unwrap();
+    let ranking = day02::rank_interpretations(&input, 10);
+    assert_eq!(ranking.len(), 7);
+    assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+    // Y=paper beats rock, X=scissors beats paper and Z=rock beats scissors
+    assert_eq!(ranking[0], (Interpretation::Moves(vec![2, 1, 0]), Some(24)));
+    assert_eq!(input.rules.describe(&ranking[0].0), "X=scissors Y=paper Z=rock");
+    assert!(ranking.contains(&(input.rules.moves_as_listed(), Some(15))));
+    assert!(ranking.contains(&(Interpretation::Outcomes, Some(12))));
+}
+
+#[test]
+fn ranks_interpretations_without_meaning_last() {
+    let input = day02::parse_with_rules("A V\n", rules_file("rpsls_rules.txt")).unwrap();
+    let ranking = day02::rank_interpretations(&input, 200);
+    assert_eq!(ranking.len(), 121);
+    assert_eq!(ranking.last().unwrap(), &(Interpretation::Outcomes, None));
+    assert_eq!(ranking[0].1, Some(10));
+}
+
+#[test]
+fn ranks_only_top_interpretations() {
+    let data = fs::read_to_string("data/day02/rpsls_test_input.txt").unwrap();
+    let input = day02::parse_with_rules(&data, rules_file("rpsls_rules.txt")).unwrap();
+    let ranking = day02::rank_interpretations(&input, 200);
+    assert_eq!(day02::rank_interpretations(&input, 5), &ranking[..5]);
+    assert_eq!(day02::rank_interpretations(&input, 1), &ranking[..1]);
+    assert!(day02::rank_interpretations(&input, 0).is_empty());
+}
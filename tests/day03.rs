@@ -0,0 +1,96 @@
+// Tests for finding the items shared by rucksacks and groups of them
+
+use std::fs;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::day03::{self, Day03, ItemSet, Rucksack};
+use advent_of_code_2022::solution::{Answer, Solution};
+
+fn test_input() -> String {
+    fs::read_to_string("data/day03/test_input.txt").unwrap()
+}
+
+#[test]
+fn item_sets() {
+    let items = ItemSet::from_items("vJrwpWtwJgWrhcsFMMfFFhFp");
+    assert_eq!(items.to_string(), "cfghprstvwFJMW");
+    assert_eq!(items.len(), 14);
+    assert!(items.contains('W') && items.contains('w'));
+    assert!(!items.contains('a') && !items.contains('1'));
+    assert_eq!(ItemSet::from_items("aZ").priorities().collect::<Vec<_>>(), vec![1, 52]);
+    assert_eq!(ItemSet::from_items("aZ").total_priority(), 53);
+    assert_eq!(ItemSet::ALL.len(), 52);
+    assert!(ItemSet::EMPTY.is_empty());
+}
+
+#[test]
+fn intersects_any_number_of_sets() {
+    let sets = ["abcXY", "bcdXZ", "cXyz"].iter().map(|items| ItemSet::from_items(items));
+    assert_eq!(ItemSet::common(sets).to_string(), "cX");
+    assert_eq!(ItemSet::common(vec![ItemSet::from_items("abc")]).to_string(), "abc");
+    assert_eq!(ItemSet::common(Vec::new()), ItemSet::EMPTY);
+
+    let thirds = day03::compartments(1, "abcbcdcde", 3).unwrap();
+    assert_eq!(thirds.len(), 3);
+    assert_eq!(ItemSet::common(thirds).to_string(), "c");
+}
+
+#[test]
+fn finds_shared_items() {
+    let rucksack = Rucksack::parse(1, "vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+    assert_eq!(rucksack.shared_items().to_string(), "p");
+    // Every shared item counts, not just the first one found
+    let rucksack = Rucksack::parse(1, "abcZcbaZ").unwrap();
+    assert_eq!(rucksack.shared_items().to_string(), "abcZ");
+    let input = day03::parse_with_group_size("abcZcbaZ", 1).unwrap();
+    assert_eq!(Day03::part1(&input), Answer::Int(1 + 2 + 3 + 52));
+}
+
+#[test]
+fn groups_of_any_size() {
+    let data = test_input();
+    let input = day03::parse_with_group_size(&data, 3).unwrap();
+    assert_eq!(Day03::part2(&input), Answer::Int(70));
+    let input = day03::parse_with_group_size(&data, 2).unwrap();
+    assert_eq!(Day03::part2(&input), Answer::Int(371));
+    let input = day03::parse_with_group_size(&data, 6).unwrap();
+    assert_eq!(Day03::part2(&input), Answer::Int(0));
+    // Part 1 does not depend on the groups
+    assert_eq!(Day03::part1(&input), Answer::Int(157));
+}
+
+#[test]
+fn incomplete_group() {
+    let e = day03::parse_with_group_size(&test_input(), 4).err().unwrap();
+    assert_eq!(e.to_string(), "<input>:7:1: expected 2 more rucksacks to make up the last group of 4");
+}
+
+#[test]
+fn reports_first_bad_line() {
+    let mut data = "abcd\n".repeat(1000);
+    data.push_str("ab1d\nabc\n");
+    let e = Day03::parse(&data).err().unwrap();
+    assert_eq!(e.to_string(), "<input>:1001:3: expected an item from a-z or A-Z");
+}
+
+#[test]
+fn bad_compartments() {
+    let check_error = |line: &str, n: usize, expected: &str| {
+        assert_eq!(day03::compartments(4, line, n).unwrap_err().to_string(), expected);
+    };
+    // Nothing is left over from the last compartment
+    check_error("abcbcdcdeZ", 3, "<input>:4:11: expected items that split into 3 compartments of the same size");
+    check_error("abc", 2, "<input>:4:4: expected items that split into 2 compartments of the same size");
+    check_error("abcd", 0, "<input>:4:1: expected at least one compartment");
+    check_error("", 2, "<input>:4:1: expected an item from a-z or A-Z");
+    // A character of more than one byte would not split on a byte offset
+    check_error("abébc", 2, "<input>:4:3: expected an item from a-z or A-Z");
+    check_error("aébc", 2, "<input>:4:2: expected an item from a-z or A-Z");
+
+    // A blank line is not an empty rucksack
+    let e = Day03::parse("abcd\n\nabcd\n").err().unwrap();
+    assert_eq!(e.to_string(), "<input>:2:1: expected an item from a-z or A-Z");
+
+    let e = day03::parse_with_group_size(&test_input(), 0).err().unwrap();
+    assert_eq!(e.to_string(), "<input>:1:1: expected groups of at least one elf");
+}
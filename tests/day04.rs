@@ -0,0 +1,74 @@
+// Tests for sweeping over every cleanup assignment in a roster
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::day04::{self, Assigned, Day04};
+use advent_of_code_2022::interval::Interval;
+use advent_of_code_2022::solution::Solution;
+
+fn audit(data: &str) -> day04::Audit {
+    day04::audit_roster(&Day04::parse(data).unwrap())
+}
+
+fn run(lo: i64, hi: i64) -> Interval {
+    Interval::new(lo, hi)
+}
+
+fn assigned(line: usize, elf: usize, lo: i64, hi: i64) -> Assigned {
+    Assigned { line, elf, sections: run(lo, hi) }
+}
+
+#[test]
+fn counts_elves_on_each_section() {
+    let audit = audit("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n");
+    assert_eq!(audit.coverage, vec![
+        (run(2, 2), 4), (run(3, 3), 5), (run(4, 5), 7), (run(6, 6), 8), (run(7, 7), 6), (run(8, 8), 4), (run(9, 9), 1),
+    ]);
+    assert_eq!(audit.coverage_of(6), 8);
+    assert_eq!(audit.coverage_of(1), 0);
+    assert_eq!(audit.coverage_of(10), 0);
+    assert_eq!(audit.max_coverage, 8);
+    assert_eq!(audit.busiest(), vec![run(6, 6)]);
+    assert!(audit.uncovered.is_empty());
+}
+
+#[test]
+fn finds_uncovered_sections() {
+    let audit = audit("1-2,10-12\n4-5,5-6\n");
+    assert_eq!(audit.coverage, vec![
+        (run(1, 2), 1), (run(3, 3), 0), (run(4, 4), 1), (run(5, 5), 2), (run(6, 6), 1), (run(7, 9), 0), (run(10, 12), 1),
+    ]);
+    assert_eq!(audit.uncovered, vec![run(3, 3), run(7, 9)]);
+    assert_eq!(audit.busiest(), vec![run(5, 5)]);
+}
+
+#[test]
+fn merges_runs_where_one_assignment_ends_as_another_starts() {
+    let audit = audit("1-3,4-6\n");
+    assert_eq!(audit.coverage, vec![(run(1, 6), 1)]);
+    assert_eq!(audit.max_coverage, 1);
+}
+
+#[test]
+fn finds_containments_across_lines() {
+    // The pair on line 1 contain each other, but are on the same line
+    let audit = audit("3-5,3-5\n1-9,4-4\n3-5,6-7\n");
+    assert_eq!(audit.containments, vec![
+        (assigned(1, 1, 3, 5), assigned(2, 2, 4, 4)),
+        (assigned(1, 1, 3, 5), assigned(3, 1, 3, 5)),
+        (assigned(1, 2, 3, 5), assigned(2, 2, 4, 4)),
+        (assigned(1, 2, 3, 5), assigned(3, 1, 3, 5)),
+        (assigned(2, 1, 1, 9), assigned(1, 1, 3, 5)),
+        (assigned(2, 1, 1, 9), assigned(1, 2, 3, 5)),
+        (assigned(2, 1, 1, 9), assigned(3, 1, 3, 5)),
+        (assigned(2, 1, 1, 9), assigned(3, 2, 6, 7)),
+        (assigned(3, 1, 3, 5), assigned(2, 2, 4, 4)),
+    ]);
+}
+
+#[test]
+fn empty_roster() {
+    let audit = audit("");
+    assert!(audit.coverage.is_empty() && audit.containments.is_empty());
+    assert_eq!(audit.max_coverage, 0);
+    assert!(audit.busiest().is_empty());
+}
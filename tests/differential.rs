@@ -0,0 +1,46 @@
+// Tests for the differential testing of reference and candidate implementations
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::differential::{self, Pair};
+use advent_of_code_2022::solution::Answer;
+
+fn num_lines(data: &str) -> Answer {
+    Answer::from(data.lines().count())
+}
+
+// Wrong once there are more than 3 lines
+fn num_lines_capped(data: &str) -> Answer {
+    Answer::from(data.lines().count().min(3))
+}
+
+#[test]
+fn pairs_agree() {
+    for pair in differential::PAIRS {
+        if let Err(divergence) = differential::compare(pair, 0..4, 1..7) {
+            panic!("day {} {}: {}", pair.day, pair.name, divergence);
+        }
+    }
+}
+
+#[test]
+fn counts_inputs() {
+    let pair = Pair { day: 2, name: "lines", reference: num_lines, candidate: num_lines };
+    assert_eq!(differential::compare(&pair, 0..3, 1..5).unwrap(), 12);
+}
+
+#[test]
+fn reports_smallest_divergence() {
+    // Day 2 has one line per round, so the first input that goes wrong has size 4
+    let pair = Pair { day: 2, name: "lines", reference: num_lines, candidate: num_lines_capped };
+    let divergence = differential::compare(&pair, 5..10, 1..20).unwrap_err();
+    assert_eq!((divergence.seed, divergence.size), (5, 4));
+    assert_eq!((divergence.reference, divergence.candidate), (Answer::Int(4), Answer::Int(3)));
+    assert_eq!(divergence.input.lines().count(), 4);
+}
+
+#[test]
+fn selects_pairs_by_day() {
+    assert!(differential::pairs(&[5]).iter().all(|pair| pair.day == 5));
+    assert!(differential::pairs(&[2]).is_empty());
+    assert_eq!(differential::pairs(&[]).len(), differential::PAIRS.len());
+}
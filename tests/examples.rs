@@ -0,0 +1,176 @@
+// Regression tests for all the days
+//
+// Runs each day's solution on the example input(s) in data/dayNN/
+// and checks them against the answers published in the puzzle text.
+
+use std::fs;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::solution::{solve, Answer, Solution};
+use advent_of_code_2022::*;
+
+fn check<S: Solution>(filename: &str, part1: Answer, part2: Answer) {
+    let data = fs::read_to_string(filename).unwrap();
+    let answers = solve::<S>(&data, None);
+    assert_eq!(answers, Ok(vec![(1, part1), (2, part2)]), "answers for {}", filename);
+}
+
+fn check_part<S: Solution>(filename: &str, part: u32, answer: Answer) {
+    let data = fs::read_to_string(filename).unwrap();
+    let answers = solve::<S>(&data, Some(part));
+    assert_eq!(answers, Ok(vec![(part, answer)]), "answer for {}", filename);
+}
+
+fn int(val: i64) -> Answer {
+    Answer::Int(val)
+}
+
+fn string(val: &str) -> Answer {
+    Answer::Str(val.to_string())
+}
+
+#[test]
+fn day01() {
+    check::<day01::Day01>("data/day01/test_input.txt", int(24000), int(45000));
+}
+
+#[test]
+fn day02() {
+    check::<day02::Day02>("data/day02/test_input.txt", int(15), int(12));
+}
+
+#[test]
+fn day03() {
+    check::<day03::Day03>("data/day03/test_input.txt", int(157), int(70));
+}
+
+#[test]
+fn day04() {
+    check::<day04::Day04>("data/day04/test_input.txt", int(2), int(4));
+}
+
+#[test]
+fn day05() {
+    check::<day05::Day05>("data/day05/test_input.txt", string("CMZ"), string("MCD"));
+}
+
+#[test]
+fn day06() {
+    check::<day06::Day06>("data/day06/test_input.txt", int(7), int(19));
+}
+
+#[test]
+fn day07() {
+    check::<day07::Day07>("data/day07/test_input.txt", int(95437), int(24933642));
+}
+
+#[test]
+fn day08() {
+    check::<day08::Day08>("data/day08/test_input.txt", int(21), int(8));
+}
+
+#[test]
+fn day09() {
+    check::<day09::Day09>("data/day09/test_input_1.txt", int(13), int(1));
+    // The larger example is only given for Part 2
+    check_part::<day09::Day09>("data/day09/test_input_2.txt", 2, int(36));
+}
+
+#[test]
+fn day10() {
+    let rendering = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+    check::<day10::Day10>("data/day10/test_input.txt", int(13140), Answer::Art(rendering.to_string()));
+}
+
+#[test]
+fn day11() {
+    check::<day11::Day11>("data/day11/test_input.txt", int(10605), int(2713310158));
+}
+
+#[test]
+fn day12() {
+    check::<day12::Day12>("data/day12/test_input.txt", int(31), int(29));
+}
+
+#[test]
+fn day13() {
+    check::<day13::Day13>("data/day13/test_input.txt", int(13), int(140));
+}
+
+#[test]
+fn day14() {
+    check::<day14::Day14>("data/day14/test_input.txt", int(24), int(93));
+}
+
+#[test]
+fn day15() {
+    check::<day15::Day15>("data/day15/test_input.txt", int(26), int(56000011));
+}
+
+// The only free point is in a corner, which the search only finds by walking
+// the perimeter of each sensor's range with x and y the right way round
+#[test]
+fn day15_beacon_in_corner() {
+    let readings = [((0, 0), (0, 5)), ((4, 0), (3, 5)), ((1, 3), (5, 0))];
+    assert_eq!(day15::search_beacons(&readings, 6), ((6, 6), 24000006));
+}
+
+#[test]
+fn day16() {
+    check::<day16::Day16>("data/day16/test_input.txt", int(1651), int(1707));
+}
+
+#[test]
+fn day17() {
+    check::<day17::Day17>("data/day17/test_input.txt", int(3068), int(1514285714288));
+}
+
+#[test]
+fn day18() {
+    check::<day18::Day18>("data/day18/test_input.txt", int(64), int(58));
+}
+
+#[test]
+fn day19() {
+    check::<day19::Day19>("data/day19/test_input.txt", int(33), int(56 * 62));
+}
+
+#[test]
+fn day20() {
+    check::<day20::Day20>("data/day20/test_input.txt", int(3), int(1623178306));
+}
+
+#[test]
+fn day21() {
+    check::<day21::Day21>("data/day21/test_input.txt", int(152), int(301));
+}
+
+// Part 2 folds the map into a cube using the puzzle input layout,
+// which is different from the example, so it has no answer here.
+#[test]
+fn day22() {
+    check::<day22::Day22>("data/day22/test_input.txt", int(6032), Answer::None);
+}
+
+#[test]
+fn day23() {
+    check::<day23::Day23>("data/day23/test_input.txt", int(110), int(20));
+    // The smaller example settles after 3 rounds, as shown in its walkthrough
+    check::<day23::Day23>("data/day23/simple_input.txt", int(25), int(4));
+}
+
+#[test]
+fn day24() {
+    check::<day24::Day24>("data/day24/test_input.txt", int(18), int(54));
+}
+
+#[test]
+fn day25() {
+    check::<day25::Day25>("data/day25/test_input.txt", string("2=-1=0"), Answer::None);
+}
@@ -0,0 +1,92 @@
+// Tests for the generated puzzle inputs
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::generate::generate;
+use advent_of_code_2022::rng::Rng;
+use advent_of_code_2022::solution::{solve, Solution};
+use advent_of_code_2022::*;
+
+fn check_parses<S: Solution>(day: u32) {
+    for seed in 0..5 {
+        for &size in &[1, 3, 10, 30] {
+            let input = generate(day, seed, size);
+            assert!(S::parse(&input).is_ok(), "day {} input with seed {} and size {}:\n{}", day, seed, size, input);
+        }
+    }
+}
+
+fn check_solves<S: Solution>(day: u32) {
+    for seed in 0..3 {
+        let input = generate(day, seed, 8);
+        assert!(solve::<S>(&input, None).is_ok(), "day {} input with seed {}:\n{}", day, seed, input);
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in 1..=25 {
+        assert_eq!(generate(day, 42, 10), generate(day, 42, 10), "day {}", day);
+    }
+    assert_ne!(generate(17, 1, 10), generate(17, 2, 10));
+
+    // The sequence is part of the format, so it must never change
+    let mut rng = Rng::new(0);
+    assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+}
+
+#[test]
+fn inputs_parse() {
+    check_parses::<day01::Day01>(1);
+    check_parses::<day02::Day02>(2);
+    check_parses::<day03::Day03>(3);
+    check_parses::<day04::Day04>(4);
+    check_parses::<day05::Day05>(5);
+    check_parses::<day06::Day06>(6);
+    check_parses::<day07::Day07>(7);
+    check_parses::<day08::Day08>(8);
+    check_parses::<day09::Day09>(9);
+    check_parses::<day10::Day10>(10);
+    check_parses::<day11::Day11>(11);
+    check_parses::<day12::Day12>(12);
+    check_parses::<day13::Day13>(13);
+    check_parses::<day14::Day14>(14);
+    check_parses::<day15::Day15>(15);
+    check_parses::<day16::Day16>(16);
+    check_parses::<day17::Day17>(17);
+    check_parses::<day18::Day18>(18);
+    check_parses::<day19::Day19>(19);
+    check_parses::<day20::Day20>(20);
+    check_parses::<day21::Day21>(21);
+    check_parses::<day22::Day22>(22);
+    check_parses::<day23::Day23>(23);
+    check_parses::<day24::Day24>(24);
+    check_parses::<day25::Day25>(25);
+}
+
+// Days 16 and 19 take too long to solve here
+#[test]
+fn inputs_solve() {
+    check_solves::<day01::Day01>(1);
+    check_solves::<day02::Day02>(2);
+    check_solves::<day03::Day03>(3);
+    check_solves::<day04::Day04>(4);
+    check_solves::<day05::Day05>(5);
+    check_solves::<day06::Day06>(6);
+    check_solves::<day07::Day07>(7);
+    check_solves::<day08::Day08>(8);
+    check_solves::<day09::Day09>(9);
+    check_solves::<day10::Day10>(10);
+    check_solves::<day11::Day11>(11);
+    check_solves::<day12::Day12>(12);
+    check_solves::<day13::Day13>(13);
+    check_solves::<day14::Day14>(14);
+    check_solves::<day15::Day15>(15);
+    check_solves::<day17::Day17>(17);
+    check_solves::<day18::Day18>(18);
+    check_solves::<day20::Day20>(20);
+    check_solves::<day21::Day21>(21);
+    check_solves::<day22::Day22>(22);
+    check_solves::<day23::Day23>(23);
+    check_solves::<day24::Day24>(24);
+    check_solves::<day25::Day25>(25);
+}
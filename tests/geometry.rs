@@ -0,0 +1,51 @@
+// Tests for the points, directions and turns
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::geometry::{Direction, Direction8, Point2, Point3, Turn};
+
+#[test]
+fn point_arithmetic() {
+    let a = Point2::new(1, 2);
+    let b = Point2::new(-3, 5);
+    assert_eq!(a + b, Point2::new(-2, 7));
+    assert_eq!(a - b, Point2::new(4, -3));
+    assert_eq!((a - b).signum(), Point2::new(1, -1));
+    assert_eq!(a * 3, Point2::new(3, 6));
+    assert_eq!(-a, Point2::new(-1, -2));
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(Point2::from_row_col(2, 5), Point2::new(5, 2));
+    assert_eq!(Point3::new(1, 1, 1).neighbours6().filter(|p| p.manhattan(Point3::new(1, 1, 1)) == 1).count(), 6);
+}
+
+#[test]
+fn steps_use_screen_coordinates() {
+    let origin = Point2::default();
+    assert_eq!(origin.step(Direction::North), Point2::new(0, -1));
+    assert_eq!(origin.step(Direction::East), Point2::new(1, 0));
+    assert_eq!(origin + Direction8::SouthWest.offset(), Point2::new(-1, 1));
+    assert_eq!(origin.neighbours4().count(), 4);
+    assert_eq!(origin.neighbours8().collect::<Vec<_>>()[1], Point2::new(1, -1));
+}
+
+#[test]
+fn turns() {
+    assert_eq!(Direction::North.turn(Turn::Right), Direction::East);
+    assert_eq!(Direction::North.turn(Turn::Left), Direction::West);
+    assert_eq!(Direction::West.turn(Turn::Right), Direction::North);
+    assert_eq!(Direction::South.turn(Turn::Around), Direction::North);
+    assert_eq!(Direction8::North.rotate(-1), Direction8::NorthWest);
+    assert_eq!(Direction8::NorthWest.rotate(1), Direction8::North);
+    assert_eq!(Direction8::from(Direction::West), Direction8::West);
+}
+
+#[test]
+fn parsing() {
+    for &dir in Direction::ALL.iter() {
+        assert_eq!(Direction::from_arrow(dir.to_arrow()), Some(dir));
+    }
+    assert_eq!(Direction::from_arrow('x'), None);
+    assert_eq!(Direction::from_udlr("U"), Some(Direction::North));
+    assert_eq!(Direction::from_udlr("N"), None);
+    assert_eq!(Turn::from_lr("R"), Some(Turn::Right));
+    assert_eq!(Turn::from_lr("5"), None);
+}
@@ -0,0 +1,75 @@
+// Tests for the 2D grid shared by the map puzzles
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::error::ParseError;
+use advent_of_code_2022::grid::Grid2D;
+
+fn digits(data: &str) -> Result<Grid2D<u32>, ParseError> {
+    Grid2D::parse(data, "a digit", |c| c.to_digit(10))
+}
+
+#[test]
+fn parse_and_display() {
+    let grid = digits("123\n456\n").unwrap();
+    assert_eq!((grid.n_rows(), grid.n_cols()), (2, 3));
+    assert_eq!(grid[(1, 0)], 4);
+    assert_eq!(grid.to_string(), "123\n456");
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(digits("123\n4x6"), Err(ParseError::new(2, 2, "a digit")));
+    assert_eq!(digits("123\n45"), Err(ParseError::new(2, 3, "rows of the same length 3")));
+    assert_eq!(digits("123\n4567"), Err(ParseError::new(2, 4, "rows of the same length 3")));
+}
+
+#[test]
+fn parse_padded() {
+    let grid = Grid2D::parse_padded("  #\n#", ' ', "a space or #", |c| match c {
+        ' ' | '#' => Some(c),
+        _ => None,
+    }).unwrap();
+    assert_eq!(grid.to_string(), "  #\n#  ");
+}
+
+#[test]
+fn bounds_checked_get() {
+    let mut grid = digits("12\n34").unwrap();
+    assert_eq!(grid.get(1, 1), Some(&4));
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+    assert!(grid.contains(0i32, 1i32));
+    assert!(!grid.contains(2usize, 0usize));
+    *grid.get_mut(0, 0).unwrap() = 9;
+    assert_eq!(grid[(0, 0)], 9);
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid2D::new(3, 3, 0);
+    assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours4(1, 1).count(), 4);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), vec![(1, 2), (2, 1), (1, 1)]);
+}
+
+#[test]
+fn rows_and_cols() {
+    let grid = digits("123\n456").unwrap();
+    let rows: Vec<u32> = grid.rows().map(|row| row.sum()).collect();
+    let cols: Vec<u32> = grid.cols().map(|col| col.sum()).collect();
+    assert_eq!(rows, vec![6, 15]);
+    assert_eq!(cols, vec![5, 7, 9]);
+    assert_eq!(grid.col(2).to_vec(), vec![3, 6]);
+    assert_eq!(grid.find(|&d| d > 4), Some((1, 1)));
+    assert_eq!(grid.crop(0..2, 1..3).to_string(), "23\n56");
+}
+
+#[test]
+fn bounding_box() {
+    let grid = digits("0000\n0100\n0010\n0000").unwrap();
+    let (rows, cols) = grid.bounding_box(|&d| d > 0).unwrap();
+    assert_eq!((rows.clone(), cols.clone()), (1..3, 1..3));
+    assert_eq!(grid.crop(rows, cols).to_string(), "10\n01");
+    assert_eq!(grid.bounding_box(|&d| d > 1), None);
+}
@@ -0,0 +1,35 @@
+// Tests for the PGM/PPM images of the grids
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::grid::Grid2D;
+use advent_of_code_2022::image;
+
+#[test]
+fn pgm() {
+    let grid = Grid2D::parse("019\n900", "a digit", |c| c.to_digit(10).map(|d| d as u8 * 25)).unwrap();
+    let mut out = Vec::new();
+    image::write_pgm(&mut out, &grid, 1).unwrap();
+    let mut expected = b"P5\n3 2\n255\n".to_vec();
+    expected.extend_from_slice(&[0, 25, 225, 225, 0, 0]);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn scaled_ppm() {
+    let mut grid = Grid2D::new(1, 2, [0, 0, 0]);
+    grid[(0, 1)] = [255, 128, 1];
+    let mut out = Vec::new();
+    image::write_ppm(&mut out, &grid, 2).unwrap();
+    let mut expected = b"P6\n4 2\n255\n".to_vec();
+    for _ in 0..2 {
+        expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 255, 128, 1, 255, 128, 1]);
+    }
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn no_dump_without_directory() {
+    assert!(!image::is_dumping());
+    image::dump_pgm("never_written", &Grid2D::new(1, 1, 0), 1);
+    assert!(!std::path::Path::new("never_written.pgm").exists());
+}
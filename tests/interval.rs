@@ -0,0 +1,50 @@
+// Tests for the inclusive intervals used by days 4 and 15
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::interval::{Interval, IntervalSet};
+
+fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+    intervals.iter().map(|&(lo, hi)| Interval::new(lo, hi)).collect()
+}
+
+#[test]
+fn interval_relations() {
+    let a = Interval::new(2, 8);
+    assert_eq!(a.len(), 7);
+    assert!(a.contains_interval(&Interval::new(3, 7)));
+    assert!(!a.contains_interval(&Interval::new(3, 9)));
+    assert!(a.overlaps(&Interval::new(8, 9)));
+    assert!(!a.overlaps(&Interval::new(9, 9)));
+    assert_eq!(a.intersection(&Interval::new(6, 12)), Some(Interval::new(6, 8)));
+    assert_eq!(a.intersection(&Interval::new(-3, 1)), None);
+}
+
+#[test]
+fn insert_merges() {
+    // Overlapping and touching intervals merge, but ones with a gap do not
+    let s = set(&[(10, 12), (1, 3), (4, 5), (8, 8), (11, 20)]);
+    assert_eq!(s.to_string(), "1-5,8-8,10-20");
+    assert_eq!(s.len(), 5 + 1 + 11);
+    let s = set(&[(10, 12), (1, 3), (0, 30)]);
+    assert_eq!(s.intervals(), &[Interval::new(0, 30)]);
+}
+
+#[test]
+fn membership() {
+    let s = set(&[(1, 5), (10, 20)]);
+    assert!(s.contains(1) && s.contains(15) && !s.contains(7) && !s.contains(21));
+    assert!(s.contains_interval(&Interval::new(12, 20)));
+    assert!(!s.contains_interval(&Interval::new(4, 10)));
+    assert!(IntervalSet::new().is_empty());
+}
+
+#[test]
+fn intersection_and_gaps() {
+    let a = set(&[(1, 5), (10, 20)]);
+    let b = set(&[(4, 12), (18, 30)]);
+    assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 12), (18, 20)]));
+    assert_eq!(a.gaps(&Interval::new(0, 25)), vec![
+        Interval::new(0, 0), Interval::new(6, 9), Interval::new(21, 25),
+    ]);
+    assert_eq!(a.gaps(&Interval::new(2, 4)), vec![]);
+}
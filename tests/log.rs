@@ -0,0 +1,29 @@
+// Tests for the verbosity levels of the diagnostic output
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::log::{self, Level};
+
+#[test]
+fn levels_from_flags() {
+    assert_eq!(log::from_flags(false, 0), Level::Warn);
+    assert_eq!(log::from_flags(false, 1), Level::Info);
+    assert_eq!(log::from_flags(false, 2), Level::Trace);
+    assert_eq!(log::from_flags(false, 5), Level::Trace);
+    assert_eq!(log::from_flags(true, 0), Level::Quiet);
+}
+
+// The level is shared by the whole process, so it is only changed in this test
+#[test]
+fn enabled_levels() {
+    assert_eq!(log::level(), Level::Warn);
+    assert!(log::enabled(Level::Warn));
+    assert!(!log::enabled(Level::Info));
+
+    log::set_level(Level::Trace);
+    assert!(log::enabled(Level::Warn) && log::enabled(Level::Info) && log::enabled(Level::Trace));
+
+    log::set_level(Level::Quiet);
+    assert!(!log::enabled(Level::Warn));
+    assert!(!log::enabled(Level::Quiet));
+    log::set_level(Level::Warn);
+}
@@ -0,0 +1,67 @@
+// Tests for the machine-readable output formats
+
+use std::time::Duration;
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::output::{format_records, format_summary, Format, Record};
+use advent_of_code_2022::solution::Answer;
+
+fn record(part: u32, answer: Answer) -> Record {
+    Record { day: 10, part, answer, elapsed: Duration::from_millis(1500) }
+}
+
+#[test]
+fn json() {
+    let records = vec![
+        record(1, Answer::Int(13140)),
+        record(2, Answer::Art("#.\n\"#".to_string())),
+    ];
+    assert_eq!(format_records(&records, Format::Json), "\
+[
+  {\"day\": 10, \"part\": 1, \"answer\": 13140, \"elapsed\": 1.500000},
+  {\"day\": 10, \"part\": 2, \"answer\": \"#.\\n\\\"#\", \"elapsed\": 1.500000}
+]");
+    assert_eq!(record(2, Answer::None).to_json(),
+        "{\"day\": 10, \"part\": 2, \"answer\": null, \"elapsed\": 1.500000}");
+}
+
+#[test]
+fn csv() {
+    let records = vec![
+        record(1, Answer::Str("a,b".to_string())),
+        record(2, Answer::None),
+    ];
+    assert_eq!(format_records(&records, Format::Csv), "\
+day,part,answer,elapsed
+10,1,\"a,b\",1.500000
+10,2,,1.500000");
+}
+
+#[test]
+fn text() {
+    let records = vec![record(1, Answer::Int(1)), record(2, Answer::Str("CMZ".to_string()))];
+    assert_eq!(format_records(&records, Format::Text), "Part 1: 1\nPart 2: CMZ");
+}
+
+#[test]
+fn parse_format() {
+    assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+    assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+    assert!("xml".parse::<Format>().is_err());
+}
+
+#[test]
+fn summary_flags_failures() {
+    let results = vec![
+        (10, Ok(vec![record(1, Answer::Int(13140)), record(2, Answer::Art("#.\n.#".to_string()))])),
+        (11, Err("panicked at src/day11.rs:1:1: oops".to_string())),
+    ];
+    let summary = format_summary(&results);
+    let lines: Vec<&str> = summary.lines().collect();
+    assert!(lines[1].starts_with(" 10     1  13140 "));
+    assert!(lines[2].starts_with(" 10     2  (see below) "));
+    assert_eq!(lines[3], "           #.");
+    assert_eq!(lines[4], "           .#");
+    assert_eq!(lines[5], " 11     -  FAILED: panicked at src/day11.rs:1:1: oops");
+    assert_eq!(lines.last(), Some(&"1 of 2 days succeeded"));
+}
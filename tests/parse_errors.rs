@@ -0,0 +1,71 @@
+// Tests for the parse errors reported on malformed inputs
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::error::ParseError;
+use advent_of_code_2022::solution::Solution;
+use advent_of_code_2022::*;
+
+fn check_error<S: Solution>(data: &str, line: usize, column: usize) {
+    match S::parse(data) {
+        Ok(_) => panic!("expected a parse error for {:?}", data),
+        Err(e) => assert_eq!((e.line, e.column), (line, column), "{}", e),
+    }
+}
+
+#[test]
+fn bad_number() {
+    check_error::<day01::Day01>("1000\n2000\n\n30x0\n", 4, 1);
+    check_error::<day20::Day20>("1\n2\n-3\nfour\n", 4, 1);
+}
+
+#[test]
+fn bad_token_column() {
+    check_error::<day04::Day04>("2-4,6-8\n2-3,4-x\n", 2, 7);
+    check_error::<day09::Day09>("R 4\nU 4\nQ 2\n", 3, 1);
+    check_error::<day15::Day15>("Sensor at x=2, y=18: closest beacon is at x=-2, y=1a5\n", 1, 51);
+}
+
+#[test]
+fn bad_separator() {
+    // Extra ranges or ends are reported where they start, not dropped
+    check_error::<day04::Day04>("1-2-3-4-5\n", 1, 4);
+    check_error::<day04::Day04>("1-2\n", 1, 4);
+    check_error::<day04::Day04>("1-2-3,4-5\n", 1, 4);
+    check_error::<day04::Day04>("1-2,3-4,5-6\n", 1, 8);
+    check_error::<day04::Day04>("1,2,3,4\n", 1, 2);
+    check_error::<day04::Day04>("1-2,3\n", 1, 6);
+    check_error::<day04::Day04>("4-2,3-4\n", 1, 1);
+}
+
+#[test]
+fn bad_grid_cell() {
+    check_error::<day08::Day08>("30373\n25512\n65a32\n", 3, 3);
+    check_error::<day23::Day23>("..#\n.x.\n", 2, 2);
+}
+
+#[test]
+fn empty_input() {
+    check_error::<day17::Day17>("", 1, 1);
+    check_error::<day17::Day17>("\n", 1, 1);
+}
+
+#[test]
+fn bad_block() {
+    // A stray blank line splits the first monkey in two
+    let monkeys = "Monkey 0:\n  Starting items: 79, 98\n\n  Operation: new = old * 19\n";
+    check_error::<day11::Day11>(monkeys, 3, 1);
+
+    // Moving crates to a stack that doesn't exist
+    let crates = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 3\n";
+    check_error::<day05::Day05>(crates, 4, 18);
+
+    // Moving more crates than the stack has by then
+    let crates = "[A]\n[B] [C]\n 1   2 \n\nmove 2 from 1 to 2\nmove 4 from 2 to 1\n";
+    check_error::<day05::Day05>(crates, 6, 6);
+}
+
+#[test]
+fn error_message() {
+    let e = ParseError::new(3, 7, "a number").in_file("data/day01/test_input.txt");
+    assert_eq!(e.to_string(), "data/day01/test_input.txt:3:7: expected a number");
+}
@@ -0,0 +1,51 @@
+// Tests for the registry of known answers
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::error::ParseError;
+use advent_of_code_2022::registry::{encode, Registry};
+use advent_of_code_2022::solution::Answer;
+
+#[test]
+fn round_trip() {
+    let mut registry = Registry::default();
+    registry.insert(10, 2, "data/day10/test_input.txt", &Answer::Art("#.\n.#".to_string()));
+    registry.insert(5, 1, "data/day05/test_input.txt", &Answer::Str("CMZ".to_string()));
+    registry.insert(1, 1, "data/day01/test_input.txt", &Answer::Int(24000));
+
+    let data = registry.to_string();
+    assert!(data.contains("\n1 1 data/day01/test_input.txt 24000\n"));
+    assert!(data.contains("\n10 2 data/day10/test_input.txt #.\\n.#\n"));
+    assert_eq!(Registry::parse(&data), Ok(registry.clone()));
+
+    assert_eq!(registry.get(5, 1, "data/day05/test_input.txt"), Some("CMZ"));
+    assert_eq!(registry.get(5, 2, "data/day05/test_input.txt"), None);
+    assert_eq!(registry.inputs(10).into_iter().collect::<Vec<_>>(), vec!["data/day10/test_input.txt"]);
+}
+
+#[test]
+fn input_with_spaces() {
+    let mut registry = Registry::default();
+    registry.insert(5, 1, "my inputs/day 5.txt", &Answer::Str("C M Z".to_string()));
+    registry.insert(5, 2, "C:\\aoc\\day05.txt", &Answer::Int(7));
+
+    let data = registry.to_string();
+    assert!(data.contains("\n5 1 my\\ inputs/day\\ 5.txt C M Z\n"));
+    assert!(data.contains("\n5 2 C:\\\\aoc\\\\day05.txt 7\n"));
+    assert_eq!(Registry::parse(&data), Ok(registry.clone()));
+    assert_eq!(registry.get(5, 1, "my inputs/day 5.txt"), Some("C M Z"));
+    assert_eq!(registry.get(5, 2, "C:\\aoc\\day05.txt"), Some("7"));
+}
+
+#[test]
+fn encoding() {
+    assert_eq!(encode(&Answer::Int(-3)), "-3");
+    assert_eq!(encode(&Answer::Str("a b\\c".to_string())), "a b\\\\c");
+    assert_eq!(encode(&Answer::None), "-");
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(Registry::parse("# comment\n\nx 1 file 5"), Err(ParseError::new(3, 1, "a day")));
+    assert_eq!(Registry::parse("1 1 file"), Err(ParseError::new(1, 9, "an answer")));
+    assert_eq!(Registry::parse("1 1 my\\ file"), Err(ParseError::new(1, 13, "an answer")));
+}
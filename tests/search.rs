@@ -0,0 +1,64 @@
+// Tests for the graph searches shared by the path finding puzzles
+
+extern crate advent_of_code_2022;
+use advent_of_code_2022::grid::Grid2D;
+use advent_of_code_2022::search::{self, Path};
+
+fn maze() -> Grid2D<char> {
+    Grid2D::parse("S.#\n#.#\n#.E", "a maze", Some).unwrap()
+}
+
+fn open_neighbours(grid: &Grid2D<char>, (row, col): (usize, usize)) -> Vec<(usize, usize)> {
+    grid.neighbours4(row, col).filter(|&pos| grid[pos] != '#').collect()
+}
+
+#[test]
+fn bfs_path() {
+    let grid = maze();
+    let path = search::bfs((0, 0), |&pos| open_neighbours(&grid, pos), |&pos| grid[pos] == 'E');
+    assert_eq!(path, Some(Path { cost: 4, nodes: vec![(0, 0), (0, 1), (1, 1), (2, 1), (2, 2)] }));
+}
+
+#[test]
+fn start_is_goal() {
+    let path = search::bfs(7, |&n| vec![n + 1], |&n| n == 7);
+    assert_eq!(path, Some(Path { cost: 0, nodes: vec![7] }));
+}
+
+#[test]
+fn unreachable_goal() {
+    let grid = Grid2D::parse("S#E", "a maze", Some).unwrap();
+    assert_eq!(search::bfs((0, 0), |&pos| open_neighbours(&grid, pos), |&pos| grid[pos] == 'E'), None);
+    assert_eq!(search::dijkstra(0, |&n: &u32| if n < 5 { vec![(n + 1, 1)] } else { vec![] }, |&n| n == 9), None);
+}
+
+#[test]
+fn dijkstra_takes_cheapest_path() {
+    // The direct edge to 3 costs more than going the long way round
+    let edges = |&n: &u32| match n {
+        0 => vec![(1, 1), (3, 10)],
+        1 => vec![(2, 2)],
+        2 => vec![(3, 3)],
+        _ => vec![],
+    };
+    let path = search::dijkstra(0, edges, |&n| n == 3);
+    assert_eq!(path, Some(Path { cost: 6, nodes: vec![0, 1, 2, 3] }));
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    let grid = Grid2D::parse("S...\n.##.\n...#\n#..E", "a maze", Some).unwrap();
+    let goal = grid.find(|&c| c == 'E').unwrap();
+    let neighbours = |&pos: &(usize, usize)| {
+        open_neighbours(&grid, pos).into_iter().map(|next| (next, 1)).collect::<Vec<_>>()
+    };
+    let manhattan = |&(row, col): &(usize, usize)| {
+        (row as i64 - goal.0 as i64).abs() + (col as i64 - goal.1 as i64).abs()
+    };
+    let astar = search::astar((0, 0), neighbours, manhattan, |&pos| pos == goal).unwrap();
+    let dijkstra = search::dijkstra((0, 0), neighbours, |&pos| pos == goal).unwrap();
+    assert_eq!(astar.cost, 6);
+    assert_eq!(astar.cost, dijkstra.cost);
+    assert_eq!(astar.nodes.len(), 7);
+    assert_eq!(astar.nodes.last(), Some(&goal));
+}